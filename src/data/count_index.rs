@@ -0,0 +1,47 @@
+//! Module dedicated to the PrimeCountIndex struct
+
+use std::ops::RangeInclusive;
+use super::PrimeData;
+
+/// Running prime counts over the bytes of some [PrimeData](crate::PrimeData)
+/// 
+/// Created by the [`PrimeData::count_index`](crate::PrimeData::count_index) method. It stores, for
+/// every byte, how many primes there are before it. Building it walks through the data once, but
+/// afterwards, finding the nth prime is just a binary search instead of counting primes all over
+/// again. So if you need to call [`PrimeData::nth_prime`](crate::PrimeData::nth_prime) lots of
+/// times on the same data, see [`PrimeData::nth_prime_indexed`](crate::PrimeData::nth_prime_indexed).
+/// 
+/// **Note**: Just like the data itself, the counts do not include the primes 2, 3, and 5.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use prime_data::PrimeData;
+/// let data = PrimeData::generate(0..=1000);
+/// let index = data.count_index();
+/// 
+/// assert_eq!(data.nth_prime_indexed(&index, 25).unwrap(), 97);
+/// assert_eq!(data.nth_prime_indexed(&index, 168).unwrap(), 997);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrimeCountIndex {
+    // prefix[i] is the amount of primes in the bytes before the ith one, so it has one more
+    // entry than the data, the last one being the total
+    pub(crate) prefix: Vec<u64>,
+    pub(crate) range: RangeInclusive<u64>,
+}
+
+impl PrimeCountIndex {
+    pub(crate) fn new(data: &PrimeData) -> Self {
+        let mut prefix = Vec::with_capacity(data.data.len() + 1);
+        let mut count = 0u64;
+
+        prefix.push(count);
+        for (_, byte) in data.iter_bytes() {
+            count += byte.count_primes();
+            prefix.push(count);
+        }
+
+        Self { prefix, range: data.range.clone() }
+    }
+}
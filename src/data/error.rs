@@ -16,7 +16,12 @@ impl std::error::Error for PrimeError {}
 
 impl fmt::Display for PrimeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Error: {}\n -> {}", self.context, self.error)
+        write!(f, "Error: {}\n -> {}", self.context, self.error)?;
+
+        match self.context.suggestion {
+            Some(suggestion) => write!(f, "\n -> {}", suggestion),
+            None => Ok(()),
+        }
     }
 }
 
@@ -27,14 +32,19 @@ impl fmt::Debug for PrimeError {
 }
 
 /// * **NotEnoughData** - This error happens when someone tries to access some range of numbers that 
-/// PrimeData does not have information about. The range it stores is the difference between the range given
-/// and the data's range.
+///   PrimeData does not have information about. The range it stores is the difference between the range given
+///   and the data's range.
 /// 
 /// * **OutOfBounds** - Same concept, except it doesn't need to be some range. If you have some set of values
-/// {1, 3, 4} and try to access the number 2, it's in the range but not in the set's bounds.
+///   {1, 3, 4} and try to access the number 2, it's in the range but not in the set's bounds.
+/// 
+/// * **TooLarge** - This error happens when generating some data would allocate more bytes than the
+///   [limit](crate::set_max_data_bytes). It stores how many bytes would be needed, and what the limit was
+///   at the time.
 pub enum ErrorType {
     NotEnoughData(RangeInclusive<u64>),
     OutOfBounds(u64),
+    TooLarge { bytes: u64, limit: u64 },
 }
 
 impl fmt::Display for ErrorType {
@@ -42,6 +52,7 @@ impl fmt::Display for ErrorType {
         match self {
             Self::NotEnoughData(range) => write!(f, "Cannot access any data in the given range: {:?}", range),
             Self::OutOfBounds(num) => write!(f, "Cannot access the given number: {}", num),
+            Self::TooLarge { bytes, limit } => write!(f, "Cannot allocate {} bytes of data, the limit is {}", bytes, limit),
         }
     }
 }
@@ -49,9 +60,16 @@ impl fmt::Display for ErrorType {
 /// Some errors can happen in more than one context, such as trying to access some number outside of a
 /// range. This will give better context to what happened, for example, when unwrapping some function that
 /// could return an error, and reading the error message.
+/// 
+/// If the error came from some existing [PrimeData](crate::PrimeData), `data_range` is the range that data
+/// covers, so the message tells both what was missing and what was actually there.
+/// 
+/// Some errors can be avoided by calling a different method, in which case `suggestion` tells which one.
 pub struct ErrorContext {
     pub action: ErrorAction,
     pub source: ErrorSource,
+    pub data_range: Option<RangeInclusive<u64>>,
+    pub suggestion: Option<&'static str>,
 }
 
 pub enum ErrorAction {
@@ -66,7 +84,12 @@ pub enum ErrorSource {
 
 impl fmt::Display for ErrorContext {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "An error occurred when trying to {} {}", self.action, self.source)
+        write!(f, "An error occurred when trying to {} {}", self.action, self.source)?;
+
+        match &self.data_range {
+            Some(range) => write!(f, " (data covers {:?})", range),
+            None => Ok(()),
+        }
     }
 }
 
@@ -0,0 +1,65 @@
+use crate::data::utils::IntSqrt;
+
+/// Evaluates the exact amount of prime numbers from 1 to N, without sieving
+/// 
+/// Instead of generating all primes up to N, like [`exact_count`](super::exact_count) does, this
+/// function uses a combinatorial method in the same family as Legendre's and Meissel's. It only needs
+/// to store `2√N` counts, taking around `O(N^(3/4))` steps, so it can count primes way beyond
+/// what would fit in memory as [PrimeData](crate::PrimeData). In release builds, it takes a few
+/// seconds for N around 10^12, and over a minute around 10^14.
+/// 
+/// # Panics
+/// 
+/// Panics if the counts would take more bytes than the [limit](crate::set_max_data_bytes), which
+/// is 16 bytes for every number up to √N. With the [default](crate::DEFAULT_MAX_DATA_BYTES) limit,
+/// that's anything from 2^56 (about 7.2 * 10^16) onwards.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use prime_data::estimate::prime_pi_exact;
+/// 
+/// assert_eq!(prime_pi_exact(1_000), 168);
+/// assert_eq!(prime_pi_exact(1_000_000_000), 50_847_534);
+/// ```
+pub fn prime_pi_exact(bound: u64) -> u64 {
+    if bound < 2 { return 0 }
+
+    let sqrt = bound.sqrt_floor() as usize;
+
+    // two vectors of sqrt + 1 counts each
+    let (bytes, limit) = (16 * (sqrt as u64 + 1), crate::max_data_bytes());
+    assert!(bytes <= limit, "Counting primes up to {} needs {} bytes, the limit is {}", bound, bytes, limit);
+
+    // at first, these count every number from 2 to v as prime, and then we cross out the multiples
+    // of each prime p, just like in a sieve, but only keeping track of the counts
+    // small[v] holds the count for v itself, and large[i] holds the count for bound / i
+    let mut small: Vec<u64> = (0..=sqrt as u64).map(|v| v.saturating_sub(1)).collect();
+    let mut large: Vec<u64> = (0..=sqrt as u64).map(|i| bound.checked_div(i).map_or(0, |v| v - 1)).collect();
+
+    for p in 2..=sqrt {
+        // if the count didn't change from p - 1 to p, then p was crossed out, so it's not prime
+        if small[p] == small[p - 1] { continue }
+
+        let primes_below = small[p - 1];
+        let square = (p * p) as u64;
+
+        for i in 1..=sqrt {
+            if bound / (i as u64) < square { break }
+
+            let quotient_count = if i * p <= sqrt {
+                large[i * p]
+            } else {
+                small[(bound / (i * p) as u64) as usize]
+            };
+
+            large[i] -= quotient_count - primes_below;
+        }
+
+        for v in (p * p..=sqrt).rev() {
+            small[v] -= small[v / p] - primes_below;
+        }
+    }
+
+    large[1]
+}
@@ -4,10 +4,16 @@
 
 use super::PrimeData;
 
-pub use upper_bound::upper_bound;
+pub use upper_bound::{upper_bound, upper_bound_f64};
 mod upper_bound;
 
-pub use nth_prime::{nth_prime_approximation, nth_prime_bounds};
+pub use pi_estimate::{pi_estimate, pi_estimate_f64, li_minus_pi};
+mod pi_estimate;
+
+pub use exact::prime_pi_exact;
+mod exact;
+
+pub use nth_prime::{nth_prime_approximation, nth_prime_bounds, nth_prime_lower, nth_prime_upper};
 mod nth_prime;
 
 /// Evaluates the exact amount of prime numbers from 1 to N
@@ -31,8 +31,23 @@ pub fn nth_prime_approximation(n: u64) -> u64 {
 
 /// Returns a range that contains the nth prime number
 /// 
-/// This is possible due to the fact that [`nth_prime_approximation`] converges to the actual nth prime as n grows
-/// over time, so we can ensure the error is at most some value epsilon.
+/// Below n = 10000, this is simply the range up to the 9999th prime. Past that, the bounds are the
+/// ones proven by Pierre Dusart and by Guy Robin and Jean-Pierre Massias:
+/// 
+/// * `n(ln n + ln ln n - 1 + (ln ln n - 2.1) / ln n) ≤ p(n)`, for n ≥ 3
+/// * `p(n) ≤ n(ln n + ln ln n - 1 + (ln ln n - 2) / ln n)`, for n ≥ 688383
+/// * `p(n) ≤ n(ln n + ln ln n - 0.9484)`, for n ≥ 39017
+/// 
+/// Otherwise, the upper bound is [`nth_prime_upper`].
+/// 
+/// # Examples
+/// 
+/// ```
+/// use prime_data::estimate::nth_prime_bounds;
+/// 
+/// assert!(nth_prime_bounds(101_476).contains(&1_320_721));
+/// assert!(nth_prime_bounds(1_000_000).contains(&15_485_863));
+/// ```
 pub fn nth_prime_bounds(n: u64) -> RangeInclusive<u64> {
 
     let log = Logarithm::log10(n);
@@ -40,18 +55,107 @@ pub fn nth_prime_bounds(n: u64) -> RangeInclusive<u64> {
     if log < 4 {
         0..=104723
     } else {
-        let approximation = nth_prime_approximation(n);
-        let two: f64 = 2.0;
-        let relative_epsilon: f64 = match log {
-            4 => two.powi(-7),
-            5 => two.powi(-10),
-            6 => two.powi(-11),
-            7 => two.powi(-13),
-            _ => two.powi(-14),
+        let x = n as f64;
+        let logn = x.ln();
+        let loglogn = logn.ln();
+
+        let lower = (x * (logn + loglogn - 1.0 + (loglogn - 2.1) / logn)).floor() as u64;
+        let upper = if n >= 688_383 {
+            (x * (logn + loglogn - 1.0 + (loglogn - 2.0) / logn)).ceil() as u64
+        } else if n >= 39_017 {
+            (x * (logn + loglogn - 0.9484)).ceil() as u64
+        } else {
+            nth_prime_upper(n)
         };
 
-        let epsilon = (approximation as f64 * relative_epsilon) as u64;
+        lower..=upper
+    }
+}
+
+/// The first five primes, for which the bounds below don't hold yet
+const FIRST_PRIMES: [u64; 5] = [2, 3, 5, 7, 11];
+
+/// Returns a value guaranteed to be greater or equal to the nth prime number
+/// 
+/// For n ≥ 6, this is `n(ln n + ln ln n)`, rounded up. Smaller values of n return the exact prime.
+/// 
+/// Useful for sizing a [generate](crate::data::PrimeData::generate) call so that it's sure to contain
+/// the nth prime, without overshooting as much as [`nth_prime_bounds`] might.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use prime_data::{PrimeData, estimate::nth_prime_upper};
+/// 
+/// let data = PrimeData::generate(0..=nth_prime_upper(1000));
+/// assert_eq!(data.nth_prime(1000), 7919);
+/// ```
+pub fn nth_prime_upper(n: u64) -> u64 {
+    if n == 0 { panic!("Tried to get the zeroth prime!") }
+    if n < 6 { return FIRST_PRIMES[n as usize - 1] }
+
+    let x = n as f64;
+    let logn = x.ln();
+
+    (x * (logn + logn.ln())).ceil() as u64
+}
 
-        (approximation - epsilon)..=(approximation + epsilon)
+/// Returns a value guaranteed to be less or equal to the nth prime number
+/// 
+/// For n ≥ 6, this is `n(ln n + ln ln n - 1)`, rounded down. Smaller values of n return the exact prime.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use prime_data::estimate::{nth_prime_lower, nth_prime_upper};
+/// 
+/// assert!(nth_prime_lower(1000) <= 7919);
+/// assert!(7919 <= nth_prime_upper(1000));
+/// ```
+pub fn nth_prime_lower(n: u64) -> u64 {
+    if n == 0 { panic!("Tried to get the zeroth prime!") }
+    if n < 6 { return FIRST_PRIMES[n as usize - 1] }
+
+    let x = n as f64;
+    let logn = x.ln();
+
+    (x * (logn + logn.ln() - 1.0)).floor() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{nth_prime_lower, nth_prime_upper, nth_prime_bounds};
+    use crate::data::PrimeData;
+
+    #[test]
+    fn nth_prime_within_bounds() {
+        let data = PrimeData::generate(0..=nth_prime_upper(20_000));
+
+        for (n, prime) in (1..=20_000).zip(data.iter_all()) {
+            assert!(nth_prime_lower(n) <= prime, "lower bound for n = {} is above {}", n, prime);
+            assert!(prime <= nth_prime_upper(n), "upper bound for n = {} is below {}", n, prime);
+        }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn nth_prime_within_combined_bounds() {
+        // goes past n = 688383, where the upper bound changes
+        let data = PrimeData::generate(0..=10_400_000);
+
+        for (n, prime) in (1..).zip(data.iter_all()) {
+            assert!(nth_prime_bounds(n).contains(&prime), "bounds for n = {} don't contain {}", n, prime);
+        }
+
+        for (n, prime) in [(10_000_000, 179_424_673), (1_000_000_000, 22_801_763_489), (1_000_000_000_000, 29_996_224_275_833)] {
+            assert!(nth_prime_bounds(n).contains(&prime));
+        }
+    }
+
+    #[test]
+    fn nth_prime_within_bounds_for_large_n() {
+        for (n, prime) in [(100_000, 1_299_709), (1_000_000, 15_485_863), (10_000_000, 179_424_673)] {
+            assert!(nth_prime_lower(n) <= prime);
+            assert!(prime <= nth_prime_upper(n));
+        }
+    }
+}
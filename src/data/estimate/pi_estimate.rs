@@ -0,0 +1,111 @@
+/// The value of the logarithmic integral at 2, li(2)
+const LI_2: f64 = 1.045_163_780_117_493;
+
+/// The Euler-Mascheroni constant γ
+const EULER_GAMMA: f64 = 0.577_215_664_901_532_9;
+
+/// Estimates the amount of primes up to `x`, a.k.a. π(x)
+/// 
+/// Unlike [`upper_bound`](super::upper_bound), this function is not guaranteed to be above or below
+/// the actual amount of primes. It's simply a close approximation of it.
+/// 
+/// If you wish to retrieve the estimate before it gets truncated, see [`pi_estimate_f64`].
+/// 
+/// # Examples
+/// 
+/// ```
+/// use prime_data::estimate::pi_estimate;
+/// 
+/// // π(10^6) = 78498
+/// assert_eq!(pi_estimate(1_000_000), 78626);
+/// ```
+pub fn pi_estimate(x: u64) -> u64 {
+    pi_estimate_f64(x) as u64
+}
+
+/// Estimates the amount of primes up to `x`, a.k.a. π(x), without truncating it
+/// 
+/// The estimate is the [offset logarithmic integral](https://en.wikipedia.org/wiki/Logarithmic_integral_function#Offset_logarithmic_integral)
+/// `Li(x) = li(x) - li(2)`, which is one of the best known approximations for π(x). For `x < 2`, it
+/// returns zero, since there are no primes there.
+/// 
+/// This is useful if you wish to chain estimates, such as evaluating `π(b) - π(a)`, without
+/// compounding rounding errors.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use prime_data::estimate::{pi_estimate, pi_estimate_f64};
+/// 
+/// assert_eq!(pi_estimate_f64(2), 0.0);
+/// 
+/// // π(2 * 10^6) - π(10^6) = 70435
+/// let difference = pi_estimate_f64(2_000_000) - pi_estimate_f64(1_000_000);
+/// assert_eq!(difference.round() as u64, 70427);
+/// ```
+pub fn pi_estimate_f64(x: u64) -> f64 {
+    if x < 2 { return 0.0 }
+
+    // Li(2) is exactly zero, but floating point error could make it slightly negative
+    (logarithmic_integral(x as f64) - LI_2).max(0.0)
+}
+
+/// Evaluates the error term `Li(x) - π(x)` of the [estimate](pi_estimate_f64)
+/// 
+/// The estimate is exact as far as floating point goes, and π(x) is [counted exactly](super::prime_pi_exact),
+/// so this takes around `O(x^(3/4))` steps, which is a few seconds for `x` around 10^12. Besides a few
+/// numbers up to 7, the error is positive for every `x` this can reach, even though it's known to
+/// change sign infinitely many times, for the first time somewhere below 10^317.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use prime_data::estimate::li_minus_pi;
+/// 
+/// // Li(1000) = 176.56...
+/// assert_eq!(li_minus_pi(1_000).floor(), 8.0);
+/// assert_eq!(li_minus_pi(1), 0.0);
+/// ```
+pub fn li_minus_pi(x: u64) -> f64 {
+    pi_estimate_f64(x) - super::prime_pi_exact(x) as f64
+}
+
+// Ramanujan's series for the logarithmic integral, which converges quite fast
+// https://en.wikipedia.org/wiki/Logarithmic_integral_function#Series_representation
+fn logarithmic_integral(x: f64) -> f64 {
+    let ln_x = x.ln();
+
+    let mut sum = 0.0;
+    let mut inner_sum = 0.0;
+    // (ln x)^n / (n! * 2^(n-1)), which for n = 0 is 2
+    let mut term = 2.0;
+
+    for n in 1..=100 {
+        term *= ln_x / (2 * n) as f64;
+        if n % 2 == 1 {
+            inner_sum += 1.0 / n as f64;
+        }
+
+        let sign = if n % 2 == 1 { 1.0 } else { -1.0 };
+        let addend = sign * term * inner_sum;
+        sum += addend;
+
+        if addend.abs() < f64::EPSILON * sum.abs() { break }
+    }
+
+    EULER_GAMMA + ln_x.ln() + x.sqrt() * sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::li_minus_pi;
+
+    #[test]
+    fn error_term_for_small_x() {
+        // Li(x) from tables of li(x), minus li(2) = 1.04516...
+        for (x, li, pi) in [(10, 5.120_435_724, 4), (100, 29.080_977_804, 25), (1_000, 176.564_494_210, 168), (1_000_000, 78_626.503_996, 78_498)] {
+            let expected = li - pi as f64;
+            assert!((li_minus_pi(x) - expected).abs() < 1e-6 * li, "{}", x);
+        }
+    }
+}
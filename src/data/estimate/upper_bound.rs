@@ -8,27 +8,64 @@ use crate::data::utils::Logarithm;
 /// This function is guaranteed to give you a value greater or equal to the actual amount of prime numbers
 /// up to the given number. The relative error is also guaranteed to be `< 0.005`, but it gets better as
 /// the given number goes to infinity.
+/// 
+/// If you wish to retrieve the estimate before it gets truncated, see [`upper_bound_f64`].
 pub fn upper_bound(bound: u64) -> u64 {
+    upper_bound_f64(bound) as u64
+}
+
+/// Estimates an upper bound for the amount of primes up to `bound`, without truncating it
+/// 
+/// This is the same estimate as [`upper_bound`], except that it keeps its fractional part. This is
+/// useful if you wish to chain estimates, such as evaluating `upper_bound(b) - upper_bound(a)`, without
+/// compounding rounding errors.
+/// 
+/// **Note**: For bounds up to 10,000, the estimate is the exact amount of primes, so it has no
+/// fractional part.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use prime_data::estimate::{upper_bound, upper_bound_f64};
+/// 
+/// assert_eq!(upper_bound_f64(1_000), 168.0);
+/// 
+/// for bound in [20_000, 350_000, 4_000_000, 1_000_000_000] {
+///     assert_eq!(upper_bound_f64(bound).floor() as u64, upper_bound(bound));
+/// }
+/// ```
+pub fn upper_bound_f64(bound: u64) -> f64 {
     if bound <= 10_000 {
-        super::exact_count(bound)
+        super::exact_count(bound) as f64
     } else {
         match Logarithm::log10(bound) {
             4 => offset_x_ln_x(bound, 1.109),
             5 => offset_x_ln_x(bound, 1.100),
-            _ => pierre_dusart(bound, (1.00, 2.51)),
+            log => pierre_dusart(bound, dusart_coefficients(log)),
         }
     }
 }
 
-fn offset_x_ln_x(bound: u64, offset: f64) -> u64 {
+// The tightest proven coefficients for the given magnitude, from Pierre Dusart's
+// "Estimates of Some Functions Over Primes without R.H." (2010)
+fn dusart_coefficients(log10: u64) -> (f64, f64) {
+    match log10 {
+        // valid for x >= 355,991
+        6..=9 => (1.00, 2.51),
+        // valid for x >= 2,953,652,287
+        _ => (1.00, 2.334),
+    }
+}
+
+fn offset_x_ln_x(bound: u64, offset: f64) -> f64 {
 
     let float = bound as f64;
     let ln_x = float.ln();
 
-    (float / (ln_x - offset)) as u64
+    float / (ln_x - offset)
 }
 
-fn pierre_dusart(bound: u64, coef: (f64, f64)) -> u64 {
+fn pierre_dusart(bound: u64, coef: (f64, f64)) -> f64 {
 
     let float = bound as f64;
     let ln_x = float.ln();
@@ -36,5 +73,21 @@ fn pierre_dusart(bound: u64, coef: (f64, f64)) -> u64 {
     let inv_ln = ln_x.recip();
     let inv_sq = inv_ln * inv_ln;
 
-    (x_ln_x * (1.0 + coef.0 * inv_ln + coef.1 * inv_sq)) as u64
-}
\ No newline at end of file
+    x_ln_x * (1.0 + coef.0 * inv_ln + coef.1 * inv_sq)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{upper_bound, pierre_dusart};
+
+    #[test]
+    fn tighter_bound_for_large_numbers() {
+        // known values of π(x)
+        for (bound, exact) in [(10_000_000_000, 455_052_511), (1_000_000_000_000, 37_607_912_018)] {
+            let estimate = upper_bound(bound);
+
+            assert!(estimate >= exact);
+            assert!(estimate < pierre_dusart(bound, (1.00, 2.51)) as u64);
+        }
+    }
+}
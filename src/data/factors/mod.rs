@@ -1,6 +1,9 @@
 //! Module dedicated to factorizing numbers
-use super::{PrimeData, utils::IntSqrt};
-use std::collections::HashMap;
+use super::{PrimeData, PrimeResult, utils::{IntSqrt, gcd, miller_rabin}};
+use std::{cmp, collections::HashMap};
+
+#[cfg(feature = "serde")]
+mod serialize;
 
 /// Retrieves every factor of x
 /// 
@@ -15,6 +18,10 @@ use std::collections::HashMap;
 /// length 2 if and only if x is prime. However, it's much faster to verify if a number
 /// is prime using [PrimeData](`crate::PrimeData`).
 /// 
+/// # Panics
+/// 
+/// Panics if x is zero, since every number divides it.
+/// 
 /// # Examples
 /// 
 /// ```
@@ -28,9 +35,62 @@ pub fn all_factors_of(x: u64) -> Vec<u64> {
     Factorization::from(x).all_factors()
 }
 
+/// Factorizes x into its prime factors
+/// 
+/// *This function is only available with the `factors` feature enabled.*
+/// 
+/// This function is simply an abstraction over `Factorization::from`, which generates all the
+/// data it needs. See [`Factorization::try_from_u64`] if you wish to return an error instead
+/// of panicking.
+/// 
+/// # Panics
+/// 
+/// Panics if x is zero, since it has no prime factorization.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use prime_data::factorize;
+/// 
+/// assert_eq!(factorize(12).as_tuples(), vec![(2, 2), (3, 1)]);
+/// assert_eq!(factorize(97).as_tuples(), vec![(97, 1)]);
+/// assert!(factorize(1).as_tuples().is_empty());
+/// ```
+pub fn factorize(x: u64) -> Factorization {
+    Factorization::from(x)
+}
+
+/// Verifies if two numbers share no prime factors
+/// 
+/// *This function is only available with the `factors` feature enabled.*
+/// 
+/// Two numbers are coprime if their greatest common divisor is 1. You could find that out by
+/// factorizing both and looking for a shared prime, but the Euclidean algorithm gets there in
+/// O(log(min(a, b))) steps, without generating any data, so that's what this function uses.
+/// 
+/// **Note**: Every number divides zero, so zero is only coprime with 1.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use prime_data::are_coprime;
+/// 
+/// assert!( are_coprime(14, 15));
+/// assert!(!are_coprime(12, 18));
+/// assert!( are_coprime(1, 1));
+/// assert!( are_coprime(0, 1));
+/// assert!(!are_coprime(0, 7));
+/// // two large primes
+/// assert!( are_coprime(999_999_999_989, 1_000_000_007));
+/// ```
+pub fn are_coprime(a: u64, b: u64) -> bool {
+    gcd(a, b) == 1
+}
+
 /// Represents some number into its prime-factorized form
 /// 
 /// *This struct is only available with the `factors` feature enabled.*
+#[derive(Clone, PartialEq, Eq)]
 pub struct Factorization {
     data: HashMap<u64, u32>
 }
@@ -70,11 +130,109 @@ impl Factorization {
     /// ```
     pub fn as_tuples(&self) -> Vec<(u64, u32)> {
         let mut vec: Vec<(u64, u32)> = self.data.iter().map(|(&p, &c)| (p, c)).collect();
-        vec.sort_by(|a, b| a.0.cmp(&(b.0)));
+        vec.sort_by_key(|&(prime, _)| prime);
 
         vec
     }
 
+    /// Retrieves the factorization as a tuple (prime, amount), with the biggest exponents first
+    /// 
+    /// Primes with the same exponent are sorted from smallest to largest. This is handy for
+    /// displaying the dominant factors of a number first. See [`Factorization::as_tuples`] if
+    /// you wish to sort them by prime instead.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::Factorization;
+    /// 
+    /// // 43560 = 2^3 * 3^2 * 5 * 11^2
+    /// let factorization = Factorization::from(43560);
+    /// assert_eq!(
+    ///     factorization.as_tuples_by_exponent(),
+    ///     vec![(2, 3), (3, 2), (11, 2), (5, 1)]
+    /// );
+    /// ```
+    pub fn as_tuples_by_exponent(&self) -> Vec<(u64, u32)> {
+        let mut vec = self.as_tuples();
+        vec.sort_by_key(|&(_, amount)| cmp::Reverse(amount));
+
+        vec
+    }
+
+    /// Borrows the raw map of the factorization, where each key is a prime and each value is
+    /// its exponent
+    /// 
+    /// If you wish to take ownership of the map, see [`Factorization::into_map`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::Factorization;
+    /// 
+    /// // 360 = 2^3 * 3^2 * 5
+    /// let factorization = Factorization::from(360);
+    /// let map = factorization.as_map();
+    /// 
+    /// assert_eq!(map.get(&2), Some(&3));
+    /// assert_eq!(map.get(&3), Some(&2));
+    /// assert_eq!(map.get(&5), Some(&1));
+    /// assert_eq!(map.get(&7), None);
+    /// 
+    /// // cloning a factorization yields an equal one
+    /// assert!(factorization.clone() == factorization);
+    /// ```
+    pub fn as_map(&self) -> &HashMap<u64, u32> {
+        &self.data
+    }
+
+    /// Consumes the factorization, returning its raw map, where each key is a prime and each
+    /// value is its exponent
+    /// 
+    /// If you only wish to inspect the map, see [`Factorization::as_map`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::Factorization;
+    /// 
+    /// // 1 has no prime factors, so its map is empty
+    /// assert!(Factorization::from(1).into_map().is_empty());
+    /// 
+    /// let map = Factorization::from(44).into_map();
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map[&2], 2);
+    /// assert_eq!(map[&11], 1);
+    /// ```
+    pub fn into_map(self) -> HashMap<u64, u32> {
+        self.data
+    }
+
+    /// Iterates over the prime factors from smallest to largest, repeating each one by its exponent
+    /// 
+    /// Multiplying every prime yielded gives back the original number, so this is the flat version
+    /// of [`Factorization::as_tuples`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::Factorization;
+    /// 
+    /// // 360 = 2^3 * 3^2 * 5
+    /// let factorization = Factorization::from(360);
+    /// assert_eq!(
+    ///     factorization.prime_factors_with_multiplicity().collect::<Vec<u64>>(),
+    ///     vec![2, 2, 2, 3, 3, 5]
+    /// );
+    /// assert_eq!(factorization.prime_factors_with_multiplicity().product::<u64>(), 360);
+    /// 
+    /// assert_eq!(Factorization::from(1).prime_factors_with_multiplicity().next(), None);
+    /// ```
+    pub fn prime_factors_with_multiplicity(&self) -> impl Iterator<Item = u64> + '_ {
+        self.as_tuples().into_iter()
+        .flat_map(|(prime, amount)| std::iter::repeat(prime).take(amount as usize))
+    }
+
     /// Retrieves all possible factors of the factorized number
     /// 
     /// It does so by multiplying every possible combination of its prime factors.
@@ -101,6 +259,112 @@ impl Factorization {
 
         vector
     }
+
+    /// Lazily iterates over all possible factors of the factorized number
+    /// 
+    /// Unlike [`Factorization::all_factors`], this doesn't collect nor sort the factors, so it's
+    /// cheaper if you only wish to scan through them. They're yielded in increasing powers of the
+    /// smallest prime first, like an odometer.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::Factorization;
+    /// 
+    /// let factorization = Factorization::from(720720);
+    /// assert_eq!(factorization.divisors_iter().count() as u64, factorization.divisor_count());
+    /// 
+    /// let mut divisors: Vec<u64> = factorization.divisors_iter().collect();
+    /// divisors.sort();
+    /// assert_eq!(divisors, factorization.all_factors());
+    /// 
+    /// assert_eq!(Factorization::from(12).divisors_iter().collect::<Vec<u64>>(), vec![1, 2, 4, 3, 6, 12]);
+    /// ```
+    pub fn divisors_iter(&self) -> impl Iterator<Item = u64> + '_ {
+        let tuples = self.as_tuples();
+        let mut exponents = vec![0u32; tuples.len()];
+        let mut next = Some(1u64);
+
+        std::iter::from_fn(move || {
+            let divisor = next?;
+
+            // increments the exponents like an odometer, resetting every exponent that's maxed out
+            next = None;
+            let mut value = divisor;
+            for (exponent, &(prime, amount)) in exponents.iter_mut().zip(tuples.iter()) {
+                if *exponent < amount {
+                    *exponent += 1;
+                    next = Some(value * prime);
+                    break;
+                }
+
+                value /= prime.pow(*exponent);
+                *exponent = 0;
+            }
+
+            Some(divisor)
+        })
+    }
+
+    /// Counts how many factors the factorized number has, without listing them
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::Factorization;
+    /// 
+    /// assert_eq!(Factorization::from(1).divisor_count(), 1);
+    /// assert_eq!(Factorization::from(29).divisor_count(), 2);
+    /// // 720720 = 2^4 * 3^2 * 5 * 7 * 11 * 13
+    /// assert_eq!(Factorization::from(720720).divisor_count(), 240);
+    /// ```
+    pub fn divisor_count(&self) -> u64 {
+        self.data.values().map(|&amount| amount as u64 + 1).product()
+    }
+
+    /// Tries to factorize the given number, generating all the data it needs
+    /// 
+    /// This is the fallible analog of `Factorization::from`, which simply unwraps this result.
+    /// Since generating data up to `sqrt(x)` always covers what the factorization needs, this should
+    /// never return an error, but it lets you use `?` instead of risking a panic.
+    /// 
+    /// The only exception is zero, which has no prime factorization, so it returns an
+    /// [OutOfBounds](crate::error::ErrorType::OutOfBounds) error. One, on the other hand, is the empty
+    /// product, so its factorization has no primes at all.
+    /// 
+    /// If the number is prime, this is found out by a primality test before generating any data,
+    /// so factorizing a prime is O(log x) instead of O(π(√x)).
+    /// 
+    /// **Note**: This can't be an implementation of [`TryFrom<u64>`], because `From<u64>` already
+    /// implies an infallible one.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::Factorization;
+    /// 
+    /// let factorization = Factorization::try_from_u64(44).unwrap();
+    /// assert!(factorization == Factorization::from(44));
+    /// assert_eq!(factorization.as_tuples(), vec![(2, 2), (11, 1)]);
+    /// 
+    /// assert!(Factorization::try_from_u64(0).is_err());
+    /// 
+    /// let one = Factorization::try_from_u64(1).unwrap();
+    /// assert!(one.as_tuples().is_empty());
+    /// assert_eq!(one.as_u64(), 1);
+    /// ```
+    pub fn try_from_u64(number: u64) -> PrimeResult<Factorization> {
+        if miller_rabin(number) {
+            let mut factorization = Factorization::new();
+            factorization.add_factor(number);
+
+            return Ok(factorization)
+        }
+
+        let prime_data = PrimeData::generate(0..=(number.sqrt_floor()));
+
+        prime_data.try_factorize(number)
+    }
 }
 
 // private methods
@@ -117,12 +381,13 @@ impl Factorization {
         }
     }
 
+    #[allow(dead_code)]
     pub(crate) fn is_empty(&self) -> bool {
         self.data.is_empty()
     }
 
     fn factor_combos(slice: &[(u64, u32)]) -> Vec<u64> {
-        if slice.len() == 0 {
+        if slice.is_empty() {
             vec![1]
         } else {
             let inner_combos = Self::factor_combos(&slice[1..]);
@@ -141,9 +406,7 @@ impl Factorization {
 
 impl From<u64> for Factorization {
     fn from(number: u64) -> Factorization {
-        let prime_data = PrimeData::generate(0..=(number.sqrt_floor()));
-
-        prime_data.factorize(number)
+        Self::try_from_u64(number).unwrap()
     }
 }
 
@@ -0,0 +1,46 @@
+//! Module dedicated to serializing factorizations with [serde](https://docs.rs/serde)
+use super::{Factorization, miller_rabin};
+use serde::{Serialize, Serializer, Deserialize, Deserializer, de::Error};
+
+/// Serializes the factorization as its [tuples](Factorization::as_tuples), sorted by prime
+/// 
+/// *This implementation is only available with both the `factors` and `serde` features enabled.*
+impl Serialize for Factorization {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_tuples().serialize(serializer)
+    }
+}
+
+/// Deserializes the factorization from its [tuples](Factorization::as_tuples)
+/// 
+/// *This implementation is only available with both the `factors` and `serde` features enabled.*
+/// 
+/// Fails if the primes are not sorted and distinct, if any of them isn't prime, if any exponent
+/// is zero, or if their product doesn't fit in a [`u64`], since no factorization could have been
+/// serialized like that.
+impl<'de> Deserialize<'de> for Factorization {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let tuples = Vec::<(u64, u32)>::deserialize(deserializer)?;
+
+        if let Some(pair) = tuples.windows(2).find(|pair| pair[0].0 >= pair[1].0) {
+            return Err(D::Error::custom(format_args!("primes must be sorted and distinct, found {} after {}", pair[1].0, pair[0].0)))
+        }
+
+        let mut product = 1u64;
+        for &(prime, amount) in tuples.iter() {
+            if !miller_rabin(prime) {
+                return Err(D::Error::custom(format_args!("{} is not prime", prime)))
+            }
+            if amount == 0 {
+                return Err(D::Error::custom(format_args!("the exponent of {} is zero", prime)))
+            }
+
+            product = match prime.checked_pow(amount).and_then(|power| product.checked_mul(power)) {
+                Some(product) => product,
+                None => return Err(D::Error::custom("factorization overflows u64")),
+            };
+        }
+
+        Ok(Self { data: tuples.into_iter().collect() })
+    }
+}
@@ -34,6 +34,34 @@ impl CoprimeIter {
 
         Self { current: (offset, index), stop_after: end }
     }
+
+    /// Creates an iterator that starts at the number `30 * offset + K_VALUES[index]`
+    /// 
+    /// This is the same position [PrimeByte](crate::PrimeByte)s use for their bits, so it's useful
+    /// if you're building your own sieve on top of them, and wish to resume iterating mid-wheel.
+    /// The iterator stops after `stop_after`, just like the end of the range in [`CoprimeIter::new`].
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index` is not less than 8, as there are only 8 [k-values](crate::K_VALUES).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::CoprimeIter;
+    /// 
+    /// // K_VALUES[6] = 23, so it starts at 30 + 23
+    /// let mut iter = CoprimeIter::from_position(1, 6, 61);
+    /// assert_eq!(iter.next(), Some(53));
+    /// assert_eq!(iter.next(), Some(59));
+    /// assert_eq!(iter.next(), Some(61));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn from_position(offset: u64, index: usize, stop_after: u64) -> Self {
+        assert!(index < 8, "Index must be less than 8, but it was {}", index);
+
+        Self { current: (offset, index), stop_after }
+    }
 }
 
 impl Iterator for CoprimeIter {
@@ -41,9 +69,12 @@ impl Iterator for CoprimeIter {
 
     fn next(&mut self) -> Option<Self::Item> {
         let (offset, index) = self.current;
-        let current_value = 30 * offset + (K_VALUES[index] as u64);
 
-        if current_value > self.stop_after { return None }
+        // near u64::MAX, the next value may not fit, which also means it's past `stop_after`
+        let current_value = match offset.checked_mul(30).and_then(|base| base.checked_add(K_VALUES[index] as u64)) {
+            Some(value) if value <= self.stop_after => value,
+            _ => return None,
+        };
 
         if index < 7 {
             self.current = (offset, index + 1);
@@ -53,4 +84,85 @@ impl Iterator for CoprimeIter {
 
         Some(current_value)
     }
-}
\ No newline at end of file
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (offset, index) = self.current;
+
+        // every wheel of 30 has 8 coprimes, so we count how many there are up to `stop_after`
+        // and take away the ones before the current position
+        let wheel_remainder = (self.stop_after % 30) as u8;
+        let up_to_end = (self.stop_after / 30).saturating_mul(8) + K_VALUES.iter().filter(|&&k| k <= wheel_remainder).count() as u64;
+        // offsets from `from_position` may be way past any number that fits
+        let before_current = offset.saturating_mul(8).saturating_add(index as u64);
+
+        let remaining = up_to_end.saturating_sub(before_current) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+// returning None never moves the cursor, so every call after that returns None as well
+impl std::iter::FusedIterator for CoprimeIter {}
+
+#[cfg(test)]
+mod tests {
+    use super::CoprimeIter;
+    use crate::K_VALUES;
+
+    #[test]
+    fn from_position_resumes_new() {
+        for skip in 0..40 {
+            let mut iter = CoprimeIter::new(0..=1000);
+            let value = iter.nth(skip).unwrap();
+
+            let (offset, index) = (value / 30, K_VALUES.binary_search(&((value % 30) as u8)).unwrap());
+            let resumed = CoprimeIter::from_position(offset, index, 1000).skip(1);
+
+            assert!(resumed.eq(iter));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_position_rejects_index() {
+        CoprimeIter::from_position(0, 8, 100);
+    }
+
+    #[test]
+    fn size_hint_is_exact() {
+        for (start, end) in [(0, 1000), (1, 1), (2, 6), (8, 22), (30, 60), (31, 31), (29, 29), (14, 15), (100, 50), (59, 1231), (961, 1000)] {
+            let iter = CoprimeIter::new(start..=end);
+            let hint = iter.size_hint();
+            let count = iter.count();
+
+            assert_eq!(hint, (count, Some(count)), "wrong hint for {}..={}", start, end);
+        }
+
+        for (offset, index) in [(u64::MAX / 30, 0), (u64::MAX / 30, 5), (1 << 61, 3), (u64::MAX, 7)] {
+            let iter = CoprimeIter::from_position(offset, index, u64::MAX);
+            let hint = iter.size_hint();
+            let count = iter.count();
+
+            assert_eq!(hint, (count, Some(count)), "wrong hint for offset {} and index {}", offset, index);
+        }
+
+        let mut iter = CoprimeIter::new(8..=100);
+        while iter.next().is_some() {
+            let (lower, _) = iter.size_hint();
+            assert_eq!(lower, CoprimeIter { current: iter.current, stop_after: 100 }.count());
+        }
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn stays_exhausted() {
+        for range in [0..=100, 8..=22, 24..=28, u64::MAX - 100..=u64::MAX] {
+            let mut iter = CoprimeIter::new(range.clone());
+            let count = iter.by_ref().count();
+
+            assert_eq!(count, range.filter(|x| [2, 3, 5].iter().all(|p| x % p != 0)).count());
+            for _ in 0..100 {
+                assert_eq!(iter.next(), None);
+            }
+        }
+    }
+}
@@ -1,5 +1,5 @@
-use std::ops::RangeInclusive;
-use crate::{PrimeData, PrimeByte, data::{error::*, utils::{Divisible, ContainsRange}}};
+use std::{ops::RangeInclusive, cmp};
+use crate::{PrimeData, PrimeByte, CoprimeIter, data::{error::*, utils::{Divisible, ContainsRange}}};
 
 /// Struct that iterates over prime numbers from some data.
 /// 
@@ -31,6 +31,8 @@ pub struct PrimeIter<'a> {
     current: (u64, usize),
     data_offset: u64,
     stop_at: u64,
+    // how many primes are left, counted once when the iterator is created
+    remaining: usize,
 }
 
 impl<'a> PrimeIter<'a> {
@@ -38,27 +40,90 @@ impl<'a> PrimeIter<'a> {
     /// 
     /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error if the given range
     /// is not contained in the PrimeData's range.
+    /// 
+    /// The only exception is a range that starts before the data, where every number before the
+    /// data start is trivially not prime: it's either 0, 1, or divisible by 2, 3, or 5, without
+    /// being 2, 3, or 5 themselves. Those numbers are simply skipped, since the data couldn't tell
+    /// anything about them anyway. Note that numbers coprime with 30 are never skipped, even if
+    /// they're not prime, because the data hasn't verified them.
+    /// 
+    /// The primes in the range are counted upfront, which only reads the data once, so the iterator
+    /// knows its exact [length](ExactSizeIterator::len), and collecting it only allocates once.
+    /// Keep in mind that this reads the whole range even if you only take a few primes from it, so
+    /// if you only need the first few primes of some wide range, a narrower range is cheaper.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::{PrimeData, PrimeIter};
+    /// let data = PrimeData::generate(100..=200);
+    /// 
+    /// // 98 and 99 are divisible by 2 and 3
+    /// let mut iter = PrimeIter::new(&data, 98..=110).unwrap();
+    /// assert_eq!(iter.next(), Some(101));
+    /// 
+    /// // 97 is prime, and the data does not have it
+    /// assert!(PrimeIter::new(&data, 97..=110).is_err());
+    /// // 91 is not prime, but the data couldn't verify that
+    /// assert!(PrimeIter::new(&data, 90..=110).is_err());
+    /// ```
     pub fn new(prime_data: &'a PrimeData, range: RangeInclusive<u64>) -> PrimeResult<Self> {
+        let (data_start, _) = prime_data.range.bounds();
+        let (range_start, range_end) = range.bounds();
+
+        let range = if range_start < data_start && has_no_prime_candidates(range_start..=cmp::min(data_start - 1, range_end)) {
+            data_start..=range_end
+        } else {
+            range
+        };
+
         if let Err(out_of_bounds) = prime_data.range.contains_range(&range) {
 
             let error = PrimeError {
-                context: ErrorContext { action: ErrorAction::Reading, source: ErrorSource::PrimeData },
+                context: ErrorContext {
+                    action: ErrorAction::Reading,
+                    source: ErrorSource::PrimeData,
+                    data_range: Some(prime_data.range.clone()),
+                    suggestion: None,
+                },
                 error: ErrorType::NotEnoughData(out_of_bounds)
             };
 
             return Err(error)
         }
 
+        // an empty range has no primes, and decoding any byte for it would be wasted work, since
+        // its start may lie past its end (or even past the data end)
+        if range.is_empty() {
+            return Ok(Self { data: &[], primes: None, current: (0, 0), data_offset: 0, stop_at: 0, remaining: 0 })
+        }
+
+        let remaining = prime_data.try_count_primes_in_range_ref(&range)? as usize;
+
         let (range_start, stop_at) = range.into_inner();
 
         let original_offset = prime_data.offset();
-        let data_start = range_start.div_floor(30) as usize - original_offset;
-        let data_end = stop_at.div_ceil(30) as usize - original_offset;
+        let data_len = prime_data.data.len();
+
+        // the range is contained in the data, but `stop_at` may still land on the last byte's upper
+        // bound, so both indexes are clamped to the data
+        let data_end = cmp::min(
+            (stop_at.div_ceil(30) as usize).saturating_sub(original_offset),
+            data_len
+        );
+        let data_start = cmp::min(
+            Divisible::div_floor(range_start, 30) as usize - original_offset,
+            data_end
+        );
 
         let data = &prime_data.data[data_start..data_end];
         let data_offset = (data_start + original_offset) as u64;
         let mut current = (0u64, 0usize);
 
+        // PrimeData does not store the primes {2, 3, 5}, so if the range includes any of those,
+        // we need to manually add them to the first vector
+        let small_primes = [2u64, 3u64, 5u64].into_iter().filter(|&x| x >= range_start);
+
         let primes = loop {
 
             if let Some(byte) = data.get(current.0 as usize) {
@@ -69,36 +134,39 @@ impl<'a> PrimeIter<'a> {
                 } else {
                     byte.as_primes(data_offset + current.0)
                 };
-                if byte_primes.len() > 0 {
-                    // PrimeData does not store the primes {2, 3, 5}, so if the range includes any
-                    // of those, we need to manually add them to the first vector
-                    let byte_primes = vec![2u64, 3u64, 5u64].into_iter()
-                    .filter(|&x| x >= range_start)
-                    .chain(byte_primes.into_iter())
-                    .collect();
-
-                    break Some(byte_primes);
+                if !byte_primes.is_empty() {
+                    break Some(small_primes.chain(byte_primes).collect());
                 }
 
                 current.0 += 1;
             } else {
-                break None;
+                // even if no byte has primes, the range may still include the small ones
+                let small_primes: Vec<u64> = small_primes.collect();
+                break if small_primes.is_empty() { None } else { Some(small_primes) };
             }
 
         };
 
-        Ok(Self { data, primes, current, data_offset, stop_at })
+        Ok(Self { data, primes, current, data_offset, stop_at, remaining })
     }
 }
 
+// Whether every number in the range is trivially not prime, being below 2 or divisible by 2, 3, or 5
+fn has_no_prime_candidates(range: RangeInclusive<u64>) -> bool {
+    let (start, end) = range.bounds();
+
+    [2, 3, 5].iter().all(|p| !range.contains(p)) && CoprimeIter::new(cmp::max(start, 2)..=end).next().is_none()
+}
+
 impl<'a> Iterator for PrimeIter<'a> {
     type Item = u64;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(vector) = &self.primes {
+        if let Some(vector) = &mut self.primes {
 
             let current_prime = vector[self.current.1];
             if current_prime > self.stop_at { return None }
+            self.remaining -= 1;
 
             if self.current.1 + 1 < vector.len() {
                 self.current.1 += 1;
@@ -106,21 +174,25 @@ impl<'a> Iterator for PrimeIter<'a> {
                 self.current.1 = 0;
                 self.current.0 += 1;
 
-                self.primes = loop {
+                // the same buffer is reused for every byte, instead of allocating a new one
+                vector.clear();
+                let exhausted = loop {
 
                     if let Some(byte) = self.data.get(self.current.0 as usize) {
-                        let byte_primes = byte.as_primes(self.data_offset + self.current.0);
+                        byte.write_primes_into(self.data_offset + self.current.0, vector);
         
-                        if byte_primes.len() > 0 {
-                            break Some(byte_primes);
+                        if !vector.is_empty() {
+                            break false;
                         }
         
                         self.current.0 += 1;
                     } else {
-                        break None;
+                        break true;
                     }
         
                 };
+
+                if exhausted { self.primes = None }
             }
 
             Some(current_prime)
@@ -129,4 +201,81 @@ impl<'a> Iterator for PrimeIter<'a> {
             None
         }
     }
-}
\ No newline at end of file
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for PrimeIter<'_> {}
+
+// once the primes run out, or go past the range end, the iterator's state never changes again
+impl std::iter::FusedIterator for PrimeIter<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::PrimeData;
+
+    #[test]
+    fn stays_exhausted() {
+        let data = PrimeData::generate(0..=1000);
+
+        for range in [0..=1000, 0..=100, 90..=96, 997..=1000, 2..=2] {
+            let mut iter = data.iter(range.clone());
+            assert_eq!(iter.by_ref().count() as u64, data.count_primes_in_range(range));
+
+            for _ in 0..10 {
+                assert_eq!(iter.next(), None);
+            }
+        }
+    }
+
+    #[test]
+    fn yields_prime_at_data_end() {
+        // a prime for every k-value, so the data end falls on each bit of its last byte
+        for prime in [61, 37, 41, 43, 47, 79, 53, 59, 1_000_081, 1_000_117, 1_000_121, 1_000_003, 1_000_037, 1_000_039, 1_000_133, 1_000_199] {
+            for start in [0, prime - 31, prime - 30, prime - 1, prime] {
+                let data = PrimeData::generate(start..=prime);
+
+                assert_eq!(data.range().1, prime);
+                assert_eq!(data.iter(start..=prime).last(), Some(prime), "{}..={}", start, prime);
+                assert_eq!(data.iter_all().last(), Some(prime), "{}..={}", start, prime);
+            }
+        }
+    }
+
+    #[test]
+    fn exact_len() {
+        let data = PrimeData::generate(0..=10_000);
+
+        for range in [0..=10_000, 0..=1, 2..=5, 7..=7, 8..=10, 29..=61, 1000..=9001, 9973..=10_000] {
+            let mut iter = data.try_iter(range.clone()).unwrap();
+            let count = data.count_primes_in_range(range.clone()) as usize;
+            assert_eq!(iter.len(), count, "{:?}", range);
+
+            for consumed in 1..=count {
+                iter.next();
+                assert_eq!(iter.len(), count - consumed, "{:?}", range);
+            }
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.len(), 0);
+        }
+
+        // starts before the data on numbers that can't be prime
+        let data = PrimeData::generate(100..=200);
+        assert_eq!(data.iter(98..=200).len(), 21);
+    }
+
+    #[test]
+    fn len_after_partial_consumption() {
+        let data = PrimeData::generate(0..=10_000);
+
+        for consumed in [0, 1, 3, 4, 100, 1229, 2000] {
+            let mut iter = data.iter_all();
+            let yielded = iter.by_ref().take(consumed).count();
+
+            assert_eq!(iter.len(), 1229 - yielded);
+            assert_eq!(iter.count(), 1229 - yielded);
+        }
+    }
+}
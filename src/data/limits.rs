@@ -0,0 +1,39 @@
+//! Module dedicated to limiting how much memory data generation may allocate
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static MAX_DATA_BYTES: AtomicU64 = AtomicU64::new(DEFAULT_MAX_DATA_BYTES);
+
+/// The default for [`max_data_bytes`], 4GiB
+pub const DEFAULT_MAX_DATA_BYTES: u64 = 1 << 32;
+
+/// Sets the maximum amount of bytes any single [PrimeData](crate::PrimeData) may allocate when
+/// being generated or expanded
+/// 
+/// Each byte stores 30 numbers, so the [default](DEFAULT_MAX_DATA_BYTES) limit of 4GiB allows ranges
+/// up to around 1.3 * 10^11 numbers wide. Anything wider than the limit returns a
+/// [TooLarge](crate::error::ErrorType::TooLarge) error instead of trying to allocate it, so a typo such
+/// as `generate(0..=u64::MAX)` doesn't take down the whole process. If you do have the memory for
+/// wider ranges, simply raise the limit with this function. Regardless of this limit, the allocation
+/// can never exceed [`isize::MAX`] bytes.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use prime_data::{PrimeData, set_max_data_bytes, max_data_bytes};
+/// 
+/// set_max_data_bytes(1_000);
+/// assert_eq!(max_data_bytes(), 1_000);
+/// 
+/// assert!(PrimeData::try_generate(0..=30_000).is_ok());
+/// assert!(PrimeData::try_generate(0..=30_030).is_err());
+/// ```
+pub fn set_max_data_bytes(bytes: u64) {
+    MAX_DATA_BYTES.store(bytes, Ordering::Relaxed);
+}
+
+/// Retrieves the maximum amount of bytes any single [PrimeData](crate::PrimeData) may allocate
+/// 
+/// See [`set_max_data_bytes`].
+pub fn max_data_bytes() -> u64 {
+    std::cmp::min(MAX_DATA_BYTES.load(Ordering::Relaxed), isize::MAX as u64)
+}
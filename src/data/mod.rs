@@ -7,17 +7,37 @@ mod prime_data;
 pub use iterators::{CoprimeIter, PrimeIter};
 mod iterators;
 
+pub use stats::PrimeStats;
+mod stats;
+
+pub use count_index::PrimeCountIndex;
+mod count_index;
+
+pub use writer::{write_primes_to, PrimeFormat};
+mod writer;
+
+mod varint;
+
+pub use limits::{set_max_data_bytes, max_data_bytes, DEFAULT_MAX_DATA_BYTES};
+mod limits;
+
 pub use error::{PrimeResult, PrimeError};
 pub mod error;
 
 pub mod estimate;
 
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "rayon")]
+pub use parallel::{set_parallel_threshold, parallel_threshold};
+
 #[cfg(feature = "factors")]
 mod factors;
 #[cfg(feature = "factors")]
-pub use factors::{Factorization, all_factors_of};
+pub use factors::{Factorization, all_factors_of, are_coprime, factorize};
 
 mod utils;
+pub use utils::{mulmod, powmod};
 
 /// A list of all values `N % 30`, where N is coprime with 2, 3, and 5
 /// 
@@ -28,30 +48,161 @@ pub const K_VALUES: [u8; 8] = [1, 7, 11, 13, 17, 19, 23, 29];
 pub use public_methods::*;
 mod public_methods {
 
-    use super::utils::IntSqrt;
+    use super::utils::{Divisible, miller_rabin};
 
     /// Verifies if `x` is a prime number
     /// 
-    /// Currently, this function is an abstraction over generating prime data up to sqrt(x) then
-    /// calling the [check prime](super::PrimeData::check_prime) method.
-    /// 
-    /// Therefore, if you need to check if lots of numbers are prime, it's heavily encouraged to
-    /// [generate](super::PrimeData::generate) prime numbers then calling that method.
+    /// First, x is checked against all primes below 100, which instantly answers for most
+    /// composites, and for every x below 101². Only if that's inconclusive, this function runs the
+    /// [Miller-Rabin test](https://en.wikipedia.org/wiki/Miller%E2%80%93Rabin_primality_test),
+    /// with enough bases to never be wrong for any `u64`. Either way, no data is generated, and
+    /// it takes O(log x) steps.
     /// 
-    /// However, it is planned to make this function faster by using primality tests instead of 
-    /// generating data. See [here](crate::guide::future).
+    /// If you already have data that contains x, [`PrimeData::is_prime`](super::PrimeData::is_prime)
+    /// is even faster, since it's a single lookup.
     /// 
     /// # Examples
     /// 
     /// ```
     /// use prime_data::is_prime;
     /// assert!( is_prime(65_537));
+    /// // the fifth Fermat number, 641 * 6_700_417
     /// assert!(!is_prime(4_294_967_297));
+    /// assert!( is_prime(18_446_744_073_709_551_557));
     /// ```
     pub fn is_prime(x: u64) -> bool {
-        let sqrt = x.sqrt_floor();
+        for prime in SMALL_PRIMES {
+            if x == prime { return true }
+            if x.divisible_by(prime) { return false }
+        }
+
+        // x has no prime factor below 101, so if it's smaller than 101², it has no factors at all
+        if x < 101 * 101 { return x > 1 }
+
+        miller_rabin(x)
+    }
+
+    /// Verifies if `x` is a prime number, in a `const` context
+    /// 
+    /// Unlike [`is_prime`], this can be evaluated at compile time, such as in a `const` assertion
+    /// that some modulus is prime. The tradeoff is that it's plain trial division by every number
+    /// coprime with 6 up to √x, taking O(√x) steps. That's fine for small numbers, or at compile
+    /// time, but for a 64 bit prime it takes billions of steps, so [`is_prime`] is the way to go at
+    /// runtime.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::is_prime_const;
+    /// 
+    /// const _: () = assert!(is_prime_const(65_537));
+    /// const _: () = assert!(!is_prime_const(65_535));
+    /// 
+    /// assert!( is_prime_const(2));
+    /// assert!(!is_prime_const(1));
+    /// assert!(!is_prime_const(1_000_000_007 * 3));
+    /// ```
+    pub const fn is_prime_const(x: u64) -> bool {
+        if x < 4 { return x > 1 }
+        if x % 2 == 0 || x % 3 == 0 { return false }
+
+        // every prime past 3 is 6k ± 1, and comparing d to x / d can't overflow like d * d could
+        let mut divisor = 5;
+        while divisor <= x / divisor {
+            if x % divisor == 0 || x % (divisor + 2) == 0 { return false }
+            divisor += 6;
+        }
+
+        true
+    }
+
+    /// Verifies if `x` is a composite number
+    /// 
+    /// A composite number is any number bigger than 1 that is not prime. Therefore, 0 and 1 are
+    /// neither prime nor composite.
+    /// 
+    /// This is simply the complement of [`is_prime`] for numbers bigger than 1.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::is_composite;
+    /// assert!(!is_composite(0));
+    /// assert!(!is_composite(1));
+    /// assert!(!is_composite(2));
+    /// assert!( is_composite(4));
+    /// assert!( is_composite(4_294_967_297));
+    /// ```
+    pub fn is_composite(x: u64) -> bool {
+        x > 1 && !is_prime(x)
+    }
 
-        super::PrimeData::generate(0..=sqrt).check_prime(x)
+    /// Verifies if `x` is either a prime number or 1
+    /// 
+    /// In other words, if `x` has no divisors other than 1 and itself. This is the same as
+    /// `!is_composite(x)`, except for 0, which is divisible by everything.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::is_prime_or_unit;
+    /// assert!(!is_prime_or_unit(0));
+    /// assert!( is_prime_or_unit(1));
+    /// assert!( is_prime_or_unit(2));
+    /// assert!(!is_prime_or_unit(4));
+    /// ```
+    pub fn is_prime_or_unit(x: u64) -> bool {
+        x == 1 || is_prime(x)
+    }
+
+    // every prime below 100, used by `is_prime` to reject composites without generating data
+    const SMALL_PRIMES: [u64; 25] = [
+        2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97
+    ];
+
+    /// Verifies if `x` is a prime number, using data you already have
+    /// 
+    /// This function is an abstraction over the [try check prime](super::PrimeData::try_check_prime)
+    /// method. Unlike [`is_prime`], it doesn't generate any data, so if you need to check lots of
+    /// numbers, you can keep reusing the same data.
+    /// 
+    /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error if
+    /// the data does not include x, nor the range between 7 and √x.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::{PrimeData, is_prime_with};
+    /// let data = PrimeData::generate(0..=1000);
+    /// 
+    /// assert!( is_prime_with(997, &data).unwrap());
+    /// assert!( is_prime_with(65_537, &data).unwrap());
+    /// assert!(!is_prime_with(999_999, &data).unwrap());
+    /// assert!( is_prime_with(999_983, &data).unwrap());
+    /// 
+    /// // 1009² is too big for the data
+    /// assert!(is_prime_with(1_018_081, &data).is_err());
+    /// ```
+    pub fn is_prime_with(x: u64, data: &super::PrimeData) -> super::PrimeResult<bool> {
+        data.try_check_prime(x)
+    }
+
+    /// Retrieves every prime number within the given range
+    /// 
+    /// This function is an abstraction for [generating](super::PrimeData::generate) prime numbers
+    /// within the range, then [collecting](super::PrimeData::primes_in_range) them, which allocates
+    /// the vector only once.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::primes_in;
+    /// assert_eq!(primes_in(10..=20), vec![11, 13, 17, 19]);
+    /// assert_eq!(primes_in(0..=10), vec![2, 3, 5, 7]);
+    /// assert!(primes_in(24..=28).is_empty());
+    /// ```
+    pub fn primes_in(range: std::ops::RangeInclusive<u64>) -> Vec<u64> {
+        super::PrimeData::generate(range.clone()).primes_in_range(range).unwrap()
     }
 
     /// Counts how many prime numbers are there less than or equal to `x`
@@ -71,4 +222,99 @@ mod public_methods {
     pub fn count_primes(x: u64) -> u64 {
         super::PrimeData::generate(0..=x).count_primes()
     }
+
+    /// Counts how many prime numbers are there within the given range
+    /// 
+    /// Unlike [`count_primes`], which always counts from 1 up to x, this only generates and counts
+    /// the primes within the range, with [`PrimeData::count_primes`](super::PrimeData::count_primes).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::{count_primes, count_primes_in};
+    /// assert_eq!(count_primes(100), 25);
+    /// assert_eq!(count_primes_in(50..=100), 10);
+    /// assert_eq!(count_primes_in(0..=100), count_primes(100));
+    /// assert_eq!(count_primes_in(24..=28), 0);
+    /// ```
+    pub fn count_primes_in(range: std::ops::RangeInclusive<u64>) -> u64 {
+        super::PrimeData::generate(range).count_primes()
+    }
+
+    /// Counts how many prime numbers are there less than or equal to `x`, picking the fastest
+    /// method for its magnitude
+    /// 
+    /// Up to [`PRIME_PI_SIEVE_THRESHOLD`], this is the same as [`count_primes`], which sieves all
+    /// primes up to x. Above it, sieving becomes too slow and memory hungry, so it switches to
+    /// [`prime_pi_exact`](crate::estimate::prime_pi_exact), which counts them without sieving.
+    /// Either way, the result is exact.
+    /// 
+    /// If you only need an approximation, see [`prime_pi_approx`].
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `x` is so large that even [`prime_pi_exact`](crate::estimate::prime_pi_exact) would
+    /// take more memory than the [limit](crate::set_max_data_bytes) allows.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::prime_pi;
+    /// assert_eq!(prime_pi(10), 4);
+    /// assert_eq!(prime_pi(1_000_000), 78_498);
+    /// assert_eq!(prime_pi(10_000_000_000), 455_052_511);
+    /// ```
+    pub fn prime_pi(x: u64) -> u64 {
+        if x <= PRIME_PI_SIEVE_THRESHOLD {
+            count_primes(x)
+        } else {
+            super::estimate::prime_pi_exact(x)
+        }
+    }
+
+    /// The biggest value for which [`prime_pi`] counts primes by sieving them
+    pub const PRIME_PI_SIEVE_THRESHOLD: u64 = 1_000_000;
+
+    /// Approximates how many prime numbers are there less than or equal to `x`
+    /// 
+    /// This is an abstraction over [`pi_estimate`](crate::estimate::pi_estimate), so it's not
+    /// guaranteed to be above or below the actual amount. If you need the exact amount, see
+    /// [`prime_pi`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::prime_pi_approx;
+    /// // the actual value is 455_052_511
+    /// assert_eq!(prime_pi_approx(10_000_000_000), 455_055_613);
+    /// ```
+    pub fn prime_pi_approx(x: u64) -> u64 {
+        super::estimate::pi_estimate(x)
+    }
+
+    /// Retrieves the nth prime number
+    /// 
+    /// This function is an abstraction for [generating](super::PrimeData::generate) prime numbers
+    /// up to the upper [nth prime bound](crate::estimate::nth_prime_bounds), then calling the
+    /// [nth prime](super::PrimeData::nth_prime) method.
+    /// 
+    /// If you only need an approximation, see [estimates](crate::estimate::nth_prime_approximation).
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `n` is zero, as the "zeroth" prime number is not defined.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::nth_prime;
+    /// assert_eq!(nth_prime(1), 2);
+    /// assert_eq!(nth_prime(25), 97);
+    /// assert_eq!(nth_prime(10001), 104743);
+    /// ```
+    pub fn nth_prime(n: u64) -> u64 {
+        let (_, end) = super::estimate::nth_prime_bounds(n).into_inner();
+
+        super::PrimeData::generate(0..=end).nth_prime(n)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,88 @@
+//! Module dedicated to expanding data in parallel
+use std::{ops::RangeInclusive, sync::atomic::{AtomicU64, Ordering}};
+use super::{PrimeData, utils::ContainsRange};
+use rayon::prelude::*;
+
+static PARALLEL_THRESHOLD: AtomicU64 = AtomicU64::new(100_000_000);
+
+/// Sets the minimum range width for which expansions will run in parallel
+/// 
+/// *This function is only available with the `rayon` feature enabled.*
+/// 
+/// Whenever you [expand](crate::PrimeData::expand) (and therefore, [generate](crate::PrimeData::generate))
+/// data over a range wider than this threshold, the range is split into chunks that are sieved on
+/// all available cores. The default threshold is 10^8. Setting it to [`u64::MAX`] disables parallelism
+/// altogether.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use prime_data::{set_parallel_threshold, parallel_threshold};
+/// 
+/// set_parallel_threshold(1_000_000);
+/// assert_eq!(parallel_threshold(), 1_000_000);
+/// ```
+pub fn set_parallel_threshold(threshold: u64) {
+    PARALLEL_THRESHOLD.store(threshold, Ordering::Relaxed);
+}
+
+/// Retrieves the minimum range width for which expansions will run in parallel
+/// 
+/// *This function is only available with the `rayon` feature enabled.*
+/// 
+/// See [`set_parallel_threshold`].
+pub fn parallel_threshold() -> u64 {
+    PARALLEL_THRESHOLD.load(Ordering::Relaxed)
+}
+
+pub(crate) fn exceeds_threshold(range: &RangeInclusive<u64>) -> bool {
+    let (start, end) = range.bounds();
+    end.saturating_sub(start) >= parallel_threshold()
+}
+
+impl PrimeData {
+    // Splits the range into chunks whose boundaries are multiples of 30, so each chunk owns whole
+    // bytes. This way, we can sieve them independently and simply concatenate their data.
+    pub(crate) fn sieve_parallel(&self, range: RangeInclusive<u64>) -> Self {
+        let (start, end) = range.bounds();
+
+        let chunk_count = 4 * rayon::current_num_threads() as u64;
+        let chunk_width = std::cmp::max((end - start) / chunk_count / 30, 1) * 30;
+
+        let aligned_start = start - start % 30;
+        let mut chunks = Vec::new();
+        let mut chunk_start = start;
+        while chunk_start <= end {
+            let chunk_end = aligned_start + (chunks.len() as u64 + 1) * chunk_width - 1;
+            let chunk_end = std::cmp::min(chunk_end, end);
+
+            chunks.push(chunk_start..=chunk_end);
+            chunk_start = chunk_end + 1;
+        }
+
+        let data = chunks.into_par_iter()
+        .map(|chunk| self.sieve(chunk).data)
+        .collect::<Vec<_>>()
+        .concat();
+
+        Self { data, range }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::PrimeData;
+
+    #[test]
+    fn parallel_matches_serial() {
+        let base = PrimeData::generate(0..=10_000);
+
+        for (start, end) in [(0, 10_000_000), (29, 3_000_017), (1_000_030, 9_999_990), (7, 37)] {
+            let serial = base.sieve(start..=end);
+            let parallel = base.sieve_parallel(start..=end);
+
+            assert_eq!(parallel.range, serial.range);
+            assert!(parallel.data == serial.data);
+        }
+    }
+}
@@ -1,6 +1,6 @@
 //! Module dedicated to the PrimeByte struct
 
-use std::ops::RangeInclusive;
+use std::{cmp, ops::RangeInclusive};
 use super::K_VALUES;
 
 /// A "byte of primes", a chunk of 8 bits corresponding to the 8 values in the (0..30) range
@@ -54,6 +54,7 @@ impl PrimeByte {
     /// // when given a non-k-value, returns an error
     /// assert!(byte.set_nonprime(4).is_err());
     /// ```
+    #[allow(clippy::result_unit_err)]
     pub fn set_nonprime(&mut self, k_value: u8) -> Result<bool, ()> {
         if let Ok(index) = K_VALUES.binary_search(&k_value) {
 
@@ -72,6 +73,29 @@ impl PrimeByte {
         }
     }
 
+    /// Sets every bit in the mask to non-prime at once
+    /// 
+    /// This is the same as calling [`PrimeByte::set_nonprime`] for the k-value of every bit set
+    /// in `mask`, but in a single operation. Returns how many bits were actually flipped, that is,
+    /// how many of them weren't already non-prime.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeByte;
+    /// 
+    /// let mut byte = PrimeByte::from(0b10110111);
+    /// // the k-values 1, 7, and 11, but the second bit is already a zero
+    /// assert_eq!(byte.set_nonprime_mask(0b11100000), 2);
+    /// assert_eq!(byte.as_u8(), 0b00010111);
+    /// ```
+    pub fn set_nonprime_mask(&mut self, mask: u8) -> u64 {
+        let flipped = (self.byte & mask).count_ones() as u64;
+        self.byte &= !mask;
+
+        flipped
+    }
+
     /// Converts the bits into boolean entries
     /// 
     /// # Examples
@@ -92,6 +116,31 @@ impl PrimeByte {
         .map(|shr| PrimeByte::is_one(byte >> shr))
     }
 
+    /// Pairs every bit with its k-value, as tuples `(k_value, is_prime)`
+    /// 
+    /// This is the same as zipping the [k-values](crate::data::K_VALUES) with
+    /// [`PrimeByte::as_boolean_array`]. Iterating over the byte itself yields these same pairs.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeByte;
+    /// let byte = PrimeByte::from(0b10100110);
+    /// assert_eq!(
+    ///     byte.pairs(),
+    ///     [(1, true), (7, false), (11, true), (13, false), (17, false), (19, true), (23, true), (29, false)]
+    /// );
+    /// 
+    /// for (k_value, is_prime) in byte {
+    ///     assert_eq!(is_prime, byte.is_prime(k_value));
+    /// }
+    /// ```
+    pub fn pairs(&self) -> [(u8, bool); 8] {
+        let booleans = self.as_boolean_array();
+
+        [0, 1, 2, 3, 4, 5, 6, 7].map(|index| (K_VALUES[index], booleans[index]))
+    }
+
     /// Retrieves all bits set to one and converts them into their respective k-values
     /// 
     /// For more information, read the [guide](crate::guide::data_structure::_1_prime_byte), or refer to the
@@ -136,11 +185,33 @@ impl PrimeByte {
     /// );
     /// ```
     pub fn as_k_values_in_range(&self, range: RangeInclusive<u8>) -> Vec<u8> {
-        self.as_boolean_array().iter()
-        .zip(K_VALUES.iter())
-        .filter(|(&is_prime, k_value)| is_prime && range.contains(k_value))
+        self.iter_k_values_in_range(range).collect()
+    }
+
+    /// Lazily iterates over all bits set to one, as their respective k-values, as long as they
+    /// fall inside the inclusive range
+    /// 
+    /// This yields the same k-values as [`PrimeByte::as_k_values_in_range`], except that it doesn't
+    /// allocate a vector, so it's cheaper if you're filtering k-values in a loop.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeByte;
+    /// let byte = PrimeByte::from(0b10100110);
+    /// let mut iter = byte.iter_k_values_in_range(2..=23);
+    /// 
+    /// assert_eq!(iter.next(), Some(11));
+    /// assert_eq!(iter.next(), Some(19));
+    /// assert_eq!(iter.next(), Some(23));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter_k_values_in_range(&self, range: RangeInclusive<u8>) -> impl Iterator<Item = u8> {
+        let masked = self.masked(range);
+
+        K_VALUES.iter().enumerate()
+        .filter(move |&(index, _)| Self::is_one(masked.byte >> (7 - index)))
         .map(|(_, &k_value)| k_value)
-        .collect()
     }
 
     /// Retrieves the k-values and converts them to actual prime numbers.
@@ -170,6 +241,31 @@ impl PrimeByte {
         .collect()
     }
 
+    /// Retrieves the k-values, converts them to actual prime numbers, and appends them into the
+    /// given buffer
+    /// 
+    /// This yields the same primes as [`PrimeByte::as_primes`], except that it doesn't allocate a new
+    /// vector. It does not clear the buffer, so you can keep reusing it across many bytes.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeByte;
+    /// let mut buffer = vec![];
+    /// 
+    /// PrimeByte::from(0b10100110).write_primes_into(21, &mut buffer);
+    /// PrimeByte::from(0b00000011).write_primes_into(22, &mut buffer);
+    /// 
+    /// assert_eq!(buffer, vec![631, 641, 649, 653, 683, 689]);
+    /// ```
+    pub fn write_primes_into(&self, offset: u64, buf: &mut Vec<u64>) {
+        for (index, &k_value) in K_VALUES.iter().enumerate() {
+            if Self::is_one(self.byte >> (7 - index)) {
+                buf.push(30 * offset + (k_value as u64));
+            }
+        }
+    }
+
     /// Retrieves the k-values, as long as they fall inside the inclusive range,
     /// and converts them to actual prime numbers
     ///
@@ -234,6 +330,33 @@ impl PrimeByte {
 
     }
 
+    /// Reverses the order of the bits in this byte
+    /// 
+    /// PrimeByte stores the bit for the smallest [k-value](crate::data::K_VALUES) (1) as its most
+    /// significant bit, and the biggest (29) as its least significant bit. Some external formats store
+    /// those 8 bits in the opposite order, so this function converts between the two conventions.
+    /// 
+    /// Since the k-values are symmetric around 15, reversing the bits is the same as mapping every
+    /// k-value `k` into `30 - k`. In other words, 1 swaps with 29, 7 with 23, 11 with 19, and 13 with 17.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeByte;
+    /// let byte = PrimeByte::from(0b10100110);
+    /// assert_eq!(byte.as_k_values(), vec![1, 11, 19, 23]);
+    /// 
+    /// let reversed = byte.reverse_bits();
+    /// assert_eq!(reversed.as_u8(), 0b01100101);
+    /// assert_eq!(reversed.as_k_values(), vec![7, 11, 19, 29]);
+    /// 
+    /// // reversing twice yields the original byte
+    /// assert_eq!(reversed.reverse_bits(), byte);
+    /// ```
+    pub fn reverse_bits(&self) -> PrimeByte {
+        PrimeByte { byte: self.byte.reverse_bits() }
+    }
+
     /// Vefifies if the given `x` is prime, based on the [k-values](crate::data::K_VALUES)
     /// 
     /// **Warning**: It will return false for 2, 3, and 5. So take care of those cases
@@ -252,13 +375,9 @@ impl PrimeByte {
     /// assert!( byte.is_prime(17));
     /// ```
     pub fn is_prime(&self, x: u8) -> bool {
-        if let Some(thing) = self.as_boolean_array().iter()
-            .zip(K_VALUES.iter())
-            .find(|(_, &k_value)| k_value == x)
-        {
-            *thing.0
-        } else {
-            false
+        match K_INDEX.get(x as usize) {
+            Some(&Some(index)) => self.byte & (0b10000000 >> index) != 0,
+            _ => false,
         }
     }
 
@@ -291,10 +410,62 @@ impl PrimeByte {
     /// assert_eq!(byte.count_primes_in_range(8..=12), 0);
     /// ```
     pub fn count_primes_in_range(&self, range: RangeInclusive<u8>) -> u64 {
-        self.as_boolean_array().iter()
-        .zip(K_VALUES.iter())
-        .filter(|(&is_prime, k_value)| is_prime && range.contains(k_value))
-        .count() as u64
+        self.popcount_in_range(range) as u64
+    }
+
+    /// Counts the number of primes whose k-values are below the given one
+    /// 
+    /// The split doesn't need to be a k-value itself, and anything past 30 counts every prime. Along
+    /// with [`PrimeByte::count_primes_at_or_above`], this splits the byte's primes in two.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeByte;
+    /// let byte = PrimeByte::from(0b11010111);
+    /// assert_eq!(byte.count_primes_below(1), 0);
+    /// assert_eq!(byte.count_primes_below(13), 2);
+    /// assert_eq!(byte.count_primes_below(14), 3);
+    /// ```
+    pub fn count_primes_below(&self, k_value: u8) -> u64 {
+        (self.byte & !MASK_FROM[cmp::min(k_value, 30) as usize]).count_ones() as u64
+    }
+
+    /// Counts the number of primes whose k-values are at or above the given one
+    /// 
+    /// The split doesn't need to be a k-value itself, and anything past 29 counts no primes. Along
+    /// with [`PrimeByte::count_primes_below`], this splits the byte's primes in two.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeByte;
+    /// let byte = PrimeByte::from(0b11010111);
+    /// assert_eq!(byte.count_primes_at_or_above(1), 6);
+    /// assert_eq!(byte.count_primes_at_or_above(13), 4);
+    /// assert_eq!(byte.count_primes_at_or_above(30), 0);
+    /// ```
+    pub fn count_primes_at_or_above(&self, k_value: u8) -> u64 {
+        (self.byte & MASK_FROM[cmp::min(k_value, 30) as usize]).count_ones() as u64
+    }
+
+    /// Counts the number of ones it has as long as their k-values fall within the range
+    /// 
+    /// This is what [`PrimeByte::count_primes_in_range`] uses under the hood. Instead of checking
+    /// each bit, it builds a mask with every k-value in the range, and counts the ones in
+    /// `byte & mask`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeByte;
+    /// let byte = PrimeByte::from(0b11010111);
+    /// assert_eq!(byte.popcount_in_range(0..=30), 6);
+    /// assert_eq!(byte.popcount_in_range(7..=17), 2);
+    /// assert_eq!(byte.popcount_in_range(8..=12), 0);
+    /// ```
+    pub fn popcount_in_range(&self, range: RangeInclusive<u8>) -> u32 {
+        (self.byte & Self::range_mask(range)).count_ones()
     }
 
     /// Converts byte into a u8
@@ -317,7 +488,8 @@ impl PrimeByte {
     /// 
     /// Range is expected to be within (0..=30), but it will not return an error or panic, if given
     /// anything above 30. If you pass it some range like (30..=199), since none of the bits fall in
-    /// that range, none will be compared, and hence the function will trivially return true.
+    /// that range, none will be compared, and hence the function will trivially return true. The
+    /// same goes for empty ranges, such as (20..=10).
     /// 
     /// There is no `Self::matches` method (without a range restriction) because that's the same as
     /// verifying if the two bytes are equal. PrimeByte implements [`Eq`].
@@ -334,16 +506,75 @@ impl PrimeByte {
     /// assert!( byte.matches_in_range(other, 30..=255));
     /// ```
     pub fn matches_in_range(&self, other: PrimeByte, range: RangeInclusive<u8>) -> bool {
-        self.as_boolean_array().iter()
-        .zip(other.as_boolean_array().iter())
-        .zip(K_VALUES.iter())
-        .filter(|(_, k_value)| range.contains(k_value))
-        .fold(true, |acc, (cur, _)| acc && (cur.0 == cur.1))
+        // the bits that differ, as long as they're in the range
+        (self.byte ^ other.byte) & Self::range_mask(range) == 0
     }
 
     fn is_one(bit: u8) -> bool {
         bit % 2 == 1
     }
+
+    // Clears every bit whose k-value does not lie in the range
+    pub(crate) fn masked(&self, range: RangeInclusive<u8>) -> PrimeByte {
+        PrimeByte { byte: self.byte & Self::range_mask(range) }
+    }
+
+    // Creates a byte with ones in every bit whose k-value lies in the range
+    fn range_mask(range: RangeInclusive<u8>) -> u8 {
+        let (start, end) = range.into_inner();
+
+        // no k-value is bigger than 29, so everything past 30 behaves just like 30
+        MASK_FROM[cmp::min(start, 30) as usize] & MASK_UP_TO[cmp::min(end, 30) as usize]
+    }
+}
+
+// MASK_FROM[n] has ones in every bit whose k-value is at least n, and MASK_UP_TO[n] in every bit whose
+// k-value is at most n. Building the mask for some range from these tables is cheaper than searching
+// through the k-values every time, which matters since it's called for every byte at the edge of some
+// range.
+const MASK_FROM: [u8; 31] = k_value_masks(true);
+const MASK_UP_TO: [u8; 31] = k_value_masks(false);
+
+const fn k_value_masks(from: bool) -> [u8; 31] {
+    let mut masks = [0u8; 31];
+
+    let mut n = 0;
+    while n <= 30 {
+        let mut bit = 0;
+        while bit < 8 {
+            let k_value = K_VALUES[bit] as usize;
+            if (from && k_value >= n) || (!from && k_value <= n) {
+                masks[n] |= 0b10000000 >> bit;
+            }
+            bit += 1;
+        }
+        n += 1;
+    }
+
+    masks
+}
+
+// K_INDEX[n] is the bit index of the k-value n, or None if n is not a k-value. It's used for reading
+// a single bit, which happens for every call to `PrimeData::is_prime`.
+const K_INDEX: [Option<u8>; 30] = k_value_indexes();
+
+const fn k_value_indexes() -> [Option<u8>; 30] {
+    let mut indexes = [None; 30];
+
+    let mut bit = 0;
+    while bit < 8 {
+        indexes[K_VALUES[bit] as usize] = Some(bit as u8);
+        bit += 1;
+    }
+
+    indexes
+}
+
+impl Default for PrimeByte {
+    /// Same as [`PrimeByte::new`]
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl From<u8> for PrimeByte {
@@ -358,6 +589,16 @@ impl From<PrimeByte> for u8 {
     }
 }
 
+impl IntoIterator for PrimeByte {
+    type Item = (u8, bool);
+    type IntoIter = std::array::IntoIter<(u8, bool), 8>;
+
+    /// Iterates over the [pairs](PrimeByte::pairs) `(k_value, is_prime)`
+    fn into_iter(self) -> Self::IntoIter {
+        self.pairs().into_iter()
+    }
+}
+
 use std::fmt;
 impl fmt::Display for PrimeByte {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -369,4 +610,224 @@ impl fmt::Debug for PrimeByte {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Display::fmt(self, f)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PrimeByte, K_VALUES, RangeInclusive};
+
+    #[test]
+    fn mask_matches_sequential_clearing() {
+        for byte in 0..=255u8 {
+            for mask in 0..=255u8 {
+                let mut masked = PrimeByte::from(byte);
+                let flipped = masked.set_nonprime_mask(mask);
+
+                let mut sequential = PrimeByte::from(byte);
+                let sequential_flipped = K_VALUES.iter().enumerate()
+                .filter(|(index, _)| mask & (0x80 >> index) != 0)
+                .filter(|(_, &k_value)| sequential.set_nonprime(k_value).unwrap())
+                .count() as u64;
+
+                assert_eq!(masked, sequential);
+                assert_eq!(flipped, sequential_flipped);
+            }
+        }
+    }
+
+    #[test]
+    fn write_primes_matches_as_primes() {
+        for byte in 0..=255u8 {
+            let byte = PrimeByte::from(byte);
+
+            for offset in [0, 1, 21, 1 << 40] {
+                let mut buffer = Vec::new();
+                byte.write_primes_into(offset, &mut buffer);
+
+                assert_eq!(buffer, byte.as_primes(offset));
+            }
+        }
+    }
+
+    #[test]
+    fn iterates_over_pairs() {
+        let pairs = PrimeByte::from(0b10100110).into_iter().collect::<Vec<(u8, bool)>>();
+        assert_eq!(pairs, vec![
+            (1, true), (7, false), (11, true), (13, false), (17, false), (19, true), (23, true), (29, false)
+        ]);
+
+        for byte in 0..=255u8 {
+            let byte = PrimeByte::from(byte);
+            let k_values = byte.into_iter().filter(|&(_, is_prime)| is_prime).map(|(k_value, _)| k_value);
+
+            assert_eq!(k_values.collect::<Vec<u8>>(), byte.as_k_values());
+        }
+    }
+
+    #[test]
+    fn popcount_matches_boolean_array() {
+        let ranges = [(0, 30), (0, 0), (1, 1), (2, 6), (7, 17), (8, 12), (12, 29), (29, 29), (30, 255), (20, 10)];
+
+        for byte in 0..=255u8 {
+            let byte = PrimeByte::from(byte);
+
+            for (start, end) in ranges {
+                let expected = byte.as_boolean_array().iter()
+                .zip(K_VALUES.iter())
+                .filter(|(&is_prime, k_value)| is_prime && (start..=end).contains(*k_value))
+                .count() as u32;
+
+                assert_eq!(byte.popcount_in_range(start..=end), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn matches_in_range_edges() {
+        let byte = PrimeByte::from(0b10111010);
+
+        // full range, only matching itself
+        assert!(byte.matches_in_range(byte, 0..=30));
+        assert!(!byte.matches_in_range(PrimeByte::from(0b10111011), 0..=30));
+
+        // a single k-value, 29 being the last bit
+        assert!(byte.matches_in_range(PrimeByte::from(0b00000000), 29..=29));
+        assert!(!byte.matches_in_range(PrimeByte::from(0b00000001), 29..=29));
+        assert!(!byte.matches_in_range(PrimeByte::from(0b00111010), 1..=1));
+
+        // vacuously true, since no k-values fall in these ranges
+        for range in [30..=255, 2..=6, 24..=28, RangeInclusive::new(20, 10)] {
+            assert!(byte.matches_in_range(PrimeByte::from(!byte.as_u8()), range));
+        }
+
+        for (a, b) in [(0u8, 255u8), (0b10101010, 0b01010101), (0b11110000, 0b11100000)] {
+            let (a, b) = (PrimeByte::from(a), PrimeByte::from(b));
+
+            for start in 0..=31u8 {
+                for end in 0..=31u8 {
+                    let expected = K_VALUES.iter().zip(a.as_boolean_array().iter().zip(b.as_boolean_array().iter()))
+                    .filter(|(k_value, _)| (start..=end).contains(*k_value))
+                    .all(|(_, (x, y))| x == y);
+
+                    assert_eq!(a.matches_in_range(b, start..=end), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn iter_k_values_matches_boolean_array() {
+        assert_eq!(PrimeByte::from(0b10100110).iter_k_values_in_range(2..=23).collect::<Vec<u8>>(), vec![11, 19, 23]);
+
+        for byte in 0..=255u8 {
+            let byte = PrimeByte::from(byte);
+
+            for (start, end) in [(0, 30), (2, 23), (7, 7), (8, 10), (29, 255), (20, 10)] {
+                let expected = byte.as_boolean_array().iter()
+                .zip(K_VALUES.iter())
+                .filter(|(&is_prime, k_value)| is_prime && (start..=end).contains(*k_value))
+                .map(|(_, &k_value)| k_value)
+                .collect::<Vec<u8>>();
+
+                assert_eq!(byte.iter_k_values_in_range(start..=end).collect::<Vec<u8>>(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn split_counts_add_up() {
+        for byte in 0..=255u8 {
+            let byte = PrimeByte::from(byte);
+
+            for k_value in 0..=31u8 {
+                let below = byte.count_primes_below(k_value);
+                let at_or_above = byte.count_primes_at_or_above(k_value);
+
+                assert_eq!(below + at_or_above, byte.count_primes());
+                assert_eq!(at_or_above, byte.count_primes_in_range(k_value..=30));
+            }
+        }
+    }
+
+    #[test]
+    fn range_mask_matches_k_values() {
+        for start in 0..=255u8 {
+            for end in 0..=255u8 {
+                let expected = K_VALUES.iter()
+                .enumerate()
+                .filter(|(_, k_value)| (start..=end).contains(*k_value))
+                .fold(0u8, |mask, (bit, _)| mask | (0b10000000 >> bit));
+
+                assert_eq!(PrimeByte::range_mask(start..=end), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn is_prime_matches_boolean_array() {
+        for byte in 0..=255u8 {
+            let byte = PrimeByte::from(byte);
+
+            for x in 0..=40u8 {
+                let expected = K_VALUES.iter()
+                .zip(byte.as_boolean_array())
+                .any(|(&k_value, is_prime)| k_value == x && is_prime);
+
+                assert_eq!(byte.is_prime(x), expected);
+            }
+        }
+    }
+
+    #[test]
+    #[ignore] // timing comparison, run with `cargo test --release -- --ignored --nocapture lookup_tables`
+    fn lookup_tables_are_faster_than_searching() {
+        use std::hint::black_box;
+        use std::time::Instant;
+
+        // how range_mask and is_prime used to work, before the lookup tables
+        fn searched_range_mask(range: RangeInclusive<u8>) -> u8 {
+            let (start, end) = range.into_inner();
+            let below = K_VALUES.partition_point(|&k| k < start) as u32;
+            let up_to = K_VALUES.partition_point(|&k| k <= end) as u32;
+            let shift = |amount: u32| 0xFFu8.checked_shr(amount).unwrap_or(0);
+            shift(below) & !shift(up_to)
+        }
+
+        fn searched_is_prime(byte: PrimeByte, x: u8) -> bool {
+            byte.as_boolean_array().iter().zip(K_VALUES.iter()).any(|(&is_prime, &k)| is_prime && k == x)
+        }
+
+        const ROUNDS: usize = 200;
+
+        let time = |f: &dyn Fn(u8, u8) -> u8| {
+            let now = Instant::now();
+            let mut acc = 0u8;
+            for _ in 0..ROUNDS {
+                for a in 0..=255u8 {
+                    for b in 0..=31u8 {
+                        acc ^= f(black_box(a), black_box(b));
+                    }
+                }
+            }
+            black_box(acc);
+            now.elapsed()
+        };
+
+        let searched_mask = time(&|a, b| searched_range_mask(b..=a));
+        let table_mask = time(&|a, b| PrimeByte::range_mask(b..=a));
+        let searched_read = time(&|a, b| searched_is_prime(PrimeByte::from(a), b) as u8);
+        let table_read = time(&|a, b| PrimeByte::from(a).is_prime(b) as u8);
+
+        println!("range_mask: searching {:?}, tables {:?}", searched_mask, table_mask);
+        println!("is_prime: searching {:?}, tables {:?}", searched_read, table_read);
+
+        assert!(table_mask < searched_mask);
+        assert!(table_read < searched_read);
+    }
+
+    #[test]
+    fn default_is_new() {
+        assert!(PrimeByte::default() == PrimeByte::new());
+        assert_eq!(PrimeByte::default().count_primes(), 8);
+    }
+}
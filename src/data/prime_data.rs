@@ -1,5 +1,5 @@
 use std::{ops::RangeInclusive, cmp};
-use super::{PrimeByte, PrimeIter, CoprimeIter, error::*, utils::{IntSqrt, ContainsRange, Divisible}};
+use super::{K_VALUES, PrimeByte, PrimeIter, CoprimeIter, PrimeStats, PrimeCountIndex, error::*, utils::{IntSqrt, ContainsRange, Divisible, miller_rabin, reverse_decimal, is_decimal_palindrome}};
 
 /// An abstraction over storing prime numbers
 /// 
@@ -73,6 +73,139 @@ impl PrimeData {
         }
     }
 
+    /// Creates the same starter data as [`PrimeData::new`], but with enough memory reserved for the
+    /// data to grow into the given range
+    /// 
+    /// This is only a hint. Reading from this data is exactly the same as reading from
+    /// [`PrimeData::new`], but if you later grow it in place, up to the end of the given range, its
+    /// bytes won't need to be reallocated.
+    /// 
+    /// See [`PrimeData::try_with_capacity`] if you wish to return an error instead of panicking.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the given range would take more bytes than the [limit](crate::set_max_data_bytes).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let data = PrimeData::with_capacity(0..=1000);
+    /// 
+    /// assert_eq!(data.range(), (0, 30));
+    /// assert_eq!(data.count_primes(), 10);
+    /// ```
+    pub fn with_capacity(range: RangeInclusive<u64>) -> Self {
+        Self::try_with_capacity(range).unwrap()
+    }
+
+    /// Tries to create the same starter data as [`PrimeData::new`], but with enough memory reserved
+    /// for the data to grow into the given range
+    /// 
+    /// Returns a [TooLarge](crate::error::ErrorType::TooLarge) error if the given range would take
+    /// more bytes than the [limit](crate::set_max_data_bytes).
+    /// 
+    /// See [`PrimeData::with_capacity`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// 
+    /// assert_eq!(PrimeData::try_with_capacity(0..=1000).unwrap().count_primes(), 10);
+    /// assert!(PrimeData::try_with_capacity(0..=u64::MAX).is_err());
+    /// ```
+    pub fn try_with_capacity(range: RangeInclusive<u64>) -> PrimeResult<Self> {
+        Self::check_data_length(&range, ErrorAction::Generating)?;
+
+        let mut data = Vec::with_capacity(cmp::max(Self::data_length(&range) as usize, 1));
+        data.extend(Self::new().data);
+
+        Ok(Self { data, range: 0..=30 })
+    }
+
+    /// Wraps bytes computed elsewhere into PrimeData for the given range
+    /// 
+    /// The bytes must follow the same layout as [`PrimeData::as_raw_bytes`]: the first one holds the
+    /// [k-values](crate::K_VALUES) from 30 * ⌊ start / 30 ⌋, and there's one byte for every 30
+    /// numbers until the end of the range. This is handy if you sieved the primes with some other
+    /// tool and wish to use this crate to read them. The bits themselves are trusted, so make
+    /// sure they're right, or the data will give wrong answers.
+    /// 
+    /// Returns a [TooLarge](crate::error::ErrorType::TooLarge) error if the data would take
+    /// more bytes than the [limit](crate::set_max_data_bytes).
+    /// 
+    /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error with the numbers left
+    /// uncovered, if there are less bytes than the range needs.
+    /// 
+    /// Returns an [OutOfBounds](crate::error::ErrorType::OutOfBounds) error with the amount of bytes,
+    /// if there are more bytes than the range needs.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// 
+    /// // 31, 37, 41, 43, 47, 53, 59 are prime, 49 is not
+    /// let data = PrimeData::from_range_and_bytes(30..=60, vec![0b11111011]).unwrap();
+    /// assert_eq!(data.count_primes(), 7);
+    /// assert!(!data.is_prime(49));
+    /// 
+    /// assert!(PrimeData::from_range_and_bytes(30..=90, vec![0b11111011]).is_err());
+    /// assert!(PrimeData::from_range_and_bytes(30..=60, vec![0, 0]).is_err());
+    /// ```
+    pub fn from_range_and_bytes(range: RangeInclusive<u64>, bytes: Vec<u8>) -> PrimeResult<Self> {
+        Self::check_data_length(&range, ErrorAction::Generating)?;
+
+        let (start, end) = range.bounds();
+        let data_length = Self::data_length(&range);
+        let byte_count = bytes.len() as u64;
+
+        let error_type = if byte_count < data_length {
+            let covered_end = 30 * (start / 30 + byte_count);
+            Some(ErrorType::NotEnoughData(cmp::max(covered_end, start)..=end))
+        } else if byte_count > data_length {
+            Some(ErrorType::OutOfBounds(byte_count))
+        } else {
+            None
+        };
+
+        if let Some(error) = error_type {
+            let error = PrimeError {
+                context: ErrorContext { action: ErrorAction::Generating, source: ErrorSource::PrimeData, data_range: None, suggestion: None },
+                error
+            };
+
+            return Err(error)
+        }
+
+        let data = bytes.into_iter().map(PrimeByte::from).collect();
+
+        Ok(Self { data, range })
+    }
+
+    /// Retrieves how many bytes generating data for the given range would take
+    /// 
+    /// Every byte covers 30 numbers, starting from the multiple of 30 right before the range, so
+    /// this lets you know how much memory some data needs before generating it. Data whose range
+    /// would need more bytes than the [limit](crate::set_max_data_bytes) can't be generated.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// 
+    /// assert_eq!(PrimeData::bytes_needed(&(0..=900)), 30);
+    /// assert_eq!(PrimeData::bytes_needed(&(29..=31)), 2);
+    /// assert_eq!(PrimeData::bytes_needed(&(100..=50)), 0);
+    /// 
+    /// let data = PrimeData::generate(1234..=5678);
+    /// assert_eq!(PrimeData::bytes_needed(&(1234..=5678)), data.stats().byte_count);
+    /// ```
+    pub fn bytes_needed(range: &RangeInclusive<u64>) -> usize {
+        Self::data_length(range) as usize
+    }
+
     /// Generates PrimeData with all prime numbers between the given range
     /// 
     /// # Examples
@@ -88,18 +221,106 @@ impl PrimeData {
     /// assert_eq!(data.count_primes(), 25);
     /// ```
     pub fn generate(range: RangeInclusive<u64>) -> Self {
+        Self::try_generate(range).unwrap()
+    }
+
+    /// Tries to generate PrimeData with all prime numbers between the given range
+    /// 
+    /// Returns a [TooLarge](crate::error::ErrorType::TooLarge) error if the data would take
+    /// more bytes than the [limit](crate::set_max_data_bytes).
+    /// 
+    /// See [`PrimeData::generate`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// 
+    /// assert_eq!(PrimeData::try_generate(0..=100).unwrap().count_primes(), 25);
+    /// assert!(PrimeData::try_generate(0..=u64::MAX).is_err());
+    /// ```
+    pub fn try_generate(range: RangeInclusive<u64>) -> PrimeResult<Self> {
+        // checking before building the base data, which can take a while for big ranges
+        Self::check_data_length(&range, ErrorAction::Generating)?;
+
         let (start, end) = range.into_inner();
 
-        if start > end {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("generate", start, end).entered();
 
+        // every number below 30 is already in the starter data, so there's nothing to sieve
+        if end < 30 {
+            let data = if Self::data_length(&(start..=end)) == 0 { vec![] } else { Self::new().data };
+            return Ok(Self { data, range: start..=end })
         }
 
-        if end <= 900 {
-            PrimeData::new().expand(start..=end)
-        } else {
-            let sqrt_end = end.sqrt_floor();
-            PrimeData::generate(0..=sqrt_end).expand(start..=end)
+        // every expansion needs all primes up to the square root of its end, so we need a chain of
+        // bounds √N, √√N, ... until it's small enough to be expanded from the starter data
+        let mut bounds = Vec::new();
+        let mut bound = end;
+        while bound > 900 {
+            bound = bound.sqrt_floor();
+            bounds.push(bound);
+        }
+
+        // then, we keep growing a single base, from the smallest bound to the biggest
+        let mut base = PrimeData::new();
+        for &bound in bounds.iter().rev() {
+            base = base.try_expand(0..=bound)?;
+        }
+
+        base.try_expand(start..=end)
+    }
+
+    /// Generates data for each of the given ranges, sharing the same base data between them
+    /// 
+    /// Generating each range separately would build the data up to its square root every time.
+    /// Instead, this generates a single base data ranging from 0 to `base_end`, and
+    /// [expands](PrimeData::try_expand) it into every range, returning their data in the same order.
+    /// So, `base_end` must be at least the square root of the largest range end.
+    /// 
+    /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error with the missing
+    /// range, if `base_end` is too small for some range.
+    /// 
+    /// Returns a [TooLarge](crate::error::ErrorType::TooLarge) error if the base, or the data for
+    /// some range, would take more bytes than the [limit](crate::set_max_data_bytes).
+    /// 
+    /// Both are checked before generating anything.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// 
+    /// let windows = [1_000_000..=1_000_100, 4_000_000..=4_000_100];
+    /// let data = PrimeData::generate_many(2_000, &windows).unwrap();
+    /// 
+    /// assert_eq!(data[0].iter_all().next(), Some(1_000_003));
+    /// assert_eq!(data[1].iter_all().next(), Some(4_000_037));
+    /// 
+    /// // √4000100 is just above 2000
+    /// assert!(PrimeData::generate_many(1_999, &windows).is_err());
+    /// ```
+    pub fn generate_many(base_end: u64, ranges: &[RangeInclusive<u64>]) -> PrimeResult<Vec<Self>> {
+        let largest_sqrt = ranges.iter().map(|range| range.end().sqrt_floor()).max().unwrap_or(0);
+
+        if largest_sqrt >= 7 && base_end < largest_sqrt {
+            let error = PrimeError {
+                context: ErrorContext { action: ErrorAction::Generating, source: ErrorSource::PrimeData, data_range: Some(0..=base_end), suggestion: None },
+                error: ErrorType::NotEnoughData(cmp::max(base_end + 1, 7)..=largest_sqrt)
+            };
+
+            return Err(error)
         }
+
+        for range in ranges {
+            Self::check_data_length(range, ErrorAction::Generating)?;
+        }
+
+        // the starter data covers up to 30 anyway, and smaller data can't expand into tiny ranges
+        let base = Self::try_generate(0..=cmp::max(base_end, 30))?;
+
+        ranges.iter().map(|range| base.try_expand(range.clone())).collect()
     }
 
   // methods for iteration
@@ -107,7 +328,8 @@ impl PrimeData {
     /// Tries to create an iterator over the given range
     /// 
     /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error if the given range
-    /// falls out of the data (self) range.
+    /// falls out of the data (self) range, unless it only starts before the data on numbers that
+    /// are trivially not prime. See [`PrimeIter::new`] for the exact condition.
     /// 
     /// See [`PrimeData::iter`].
     pub fn try_iter<'a>(&'a self, range: RangeInclusive<u64>) -> PrimeResult<PrimeIter<'a>> {
@@ -121,6 +343,10 @@ impl PrimeData {
     /// 
     /// If you wish to iterate over all primes within the given range, see [`PrimeData::iter_all`].
     /// 
+    /// Creating the iterator counts every prime in the range, so it knows its exact length. That
+    /// reads the whole range once, which is way cheaper than iterating through it, but it's still
+    /// wasted if you only need the first few primes of a wide range. See [`PrimeIter::new`].
+    /// 
     /// # Panics
     /// 
     /// Panics if the given range falls out of the data (self) range.
@@ -176,560 +402,1819 @@ impl PrimeData {
         self.iter(self.range.clone())
     }
 
-  // methods for expansion/generation
-
-    /// Tries to expand the current PrimeData into more PrimeData
+    /// Iterates over the primes in the given range, skipping the first `skip` of them
     /// 
-    /// The expanded data will contain all primes in `range`
+    /// This yields the same primes as `self.try_iter(range)?.skip(skip)`, but instead of going
+    /// through the skipped primes one by one, it counts them byte by byte, jumping straight to the
+    /// byte that has the first prime it needs to yield. So it's way faster when `skip` is large.
     /// 
     /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error if
     /// the given range falls out of the data (self) range.
     /// 
-    /// See [`PrimeData::expand`].
-    pub fn try_expand(&self, range: RangeInclusive<u64>) -> PrimeResult<Self> {
-
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let data = PrimeData::generate(0..=100);
+    /// let mut iter = data.iter_skip(10, 0..=100).unwrap();
+    /// 
+    /// // 31 is the 11th prime
+    /// assert_eq!(iter.next(), Some(31));
+    /// assert_eq!(iter.next(), Some(37));
+    /// assert_eq!(data.iter_skip(24, 0..=100).unwrap().collect::<Vec<u64>>(), vec![97]);
+    /// assert_eq!(data.iter_skip(25, 0..=100).unwrap().next(), None);
+    /// ```
+    pub fn iter_skip<'a>(&'a self, skip: u64, range: RangeInclusive<u64>) -> PrimeResult<impl Iterator<Item = u64> + 'a> {
         let (start, end) = range.bounds();
-        let end_sqrt = end.sqrt_floor();
-
-        if let Err(missing_range) = self.range.contains_range(&(7..=end_sqrt)) {
+        let primes = self.try_iter(range)?;
 
-            let error = PrimeError {
-                context: ErrorContext { action: ErrorAction::Modifying, source: ErrorSource::PrimeData },
-                error: ErrorType::NotEnoughData(missing_range)
-            };
+        // primedata does not take 2, 3, and 5 into account
+        let small_primes = [2, 3, 5].iter().filter(|&&p| start <= p && p <= end).count() as u64;
 
-            return Err(error)
+        // from here on, we don't need to worry about 2, 3, and 5
+        let mut byte_start = cmp::max(start, 6);
+        if skip <= small_primes || byte_start > end {
+            return Ok(primes.skip(skip as usize))
         }
 
-        let mut expanded_data = Self::create_empty(range);
-        if expanded_data.is_empty() { return Ok(expanded_data) }
+        // jumps over every byte whose primes will all be skipped
+        let mut remaining = skip - small_primes;
+        while let Some(byte) = self.data.get((byte_start / 30) as usize - self.offset()) {
+            let count = byte.popcount_in_range((byte_start % 30) as u8..=30) as u64;
+            if count > remaining || byte_start > end { break }
 
-        for prime in self.iter(7..=end_sqrt) {
-            let lower_bound = cmp::max(start.div_ceil(prime), 7);
-            let upper_bound = end.div_floor(prime);
-            for multiplier in CoprimeIter::new(lower_bound..=upper_bound) {
-                let composite_number = prime * multiplier;
-                expanded_data.set_nonprime(composite_number).unwrap();
-            }
+            remaining -= count;
+            byte_start = 30 * (byte_start / 30 + 1);
         }
 
-        Ok(expanded_data)
+        Ok(self.try_iter(byte_start..=end)?.skip(remaining as usize))
     }
 
-    /// Expands the current PrimeData into more PrimeData
-    /// 
-    /// The expanded data will contain all primes in the given range.
+    /// Iterates over every `step`th prime in the given range, starting with the first one
     /// 
-    /// See [`PrimeData::try_expand`] if you wish to return an error instead of panicking.
+    /// This is the same as `self.try_iter(range)?.step_by(step)`, which is handy for sampling
+    /// primes without collecting all of them.
     /// 
-    /// # Panics
-    ///
-    /// Panics if the given PrimeData does not have enough data to expand into the given range.
+    /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error if
+    /// the given range falls out of the data (self) range.
     /// 
-    /// More specifically, if you wish to expand it to some prime data with range (X..=Y), the
-    /// PrimeData (self) must have a range from 7 to √Y or more.
+    /// Returns an [OutOfBounds](crate::error::ErrorType::OutOfBounds) error if `step` is zero.
     /// 
     /// # Examples
     /// 
     /// ```
     /// use prime_data::PrimeData;
+    /// let data = PrimeData::generate(0..=100);
+    /// let mut iter = data.iter_step(0..=30, 3).unwrap();
     /// 
-    /// let starter_data = PrimeData::new();
-    /// let expanded_data = starter_data.expand(59..=90);
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert_eq!(iter.next(), Some(7));
+    /// assert_eq!(iter.next(), Some(17));
+    /// assert_eq!(iter.next(), Some(29));
+    /// assert_eq!(iter.next(), None);
     /// 
-    /// assert_eq!(
-    ///     expanded_data.iter_all().collect::<Vec<u64>>(),
-    ///     vec![59, 61, 67, 71, 73, 79, 83, 89]
-    /// );
+    /// assert!(data.iter_step(0..=30, 0).is_err());
     /// ```
-    pub fn expand(&self, range: RangeInclusive<u64>) -> Self {
-        self.try_expand(range).unwrap()
-    }
+    pub fn iter_step<'a>(&'a self, range: RangeInclusive<u64>, step: usize) -> PrimeResult<impl Iterator<Item = u64> + 'a> {
+        if step == 0 {
+            let error = PrimeError {
+                context: ErrorContext { action: ErrorAction::Reading, source: ErrorSource::PrimeData, data_range: Some(self.range.clone()), suggestion: None },
+                error: ErrorType::OutOfBounds(0)
+            };
 
-  // general methods
+            return Err(error)
+        }
 
-    /// Destructures the PrimeData range into (start, end)
+        Ok(self.try_iter(range)?.step_by(step))
+    }
+
+    /// Iterates over the emirps in the given range
     /// 
-    /// **Note**: The range is inclusive.
+    /// An emirp is a prime whose decimal digits, reversed, make up a different prime, such as 13 and 31.
+    /// Palindromic primes, such as 11 or 101, are not emirps. The reversed numbers may lie far outside
+    /// the data, so they're verified with a primality test instead.
+    /// 
+    /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error if
+    /// the given range falls out of the data (self) range.
     /// 
     /// # Examples
     /// 
     /// ```
     /// use prime_data::PrimeData;
-    /// let expansion = PrimeData::generate(173..=244);
-    /// assert_eq!(expansion.range(), (173, 244));
+    /// let data = PrimeData::generate(0..=200);
+    /// 
+    /// let emirps: Vec<u64> = data.iter_emirps(0..=100).unwrap().collect();
+    /// assert_eq!(emirps, vec![13, 17, 31, 37, 71, 73, 79, 97]);
+    /// 
+    /// // 101 and 131 are palindromes, 103 reversed is 301 = 7 * 43
+    /// assert_eq!(data.iter_emirps(100..=200).unwrap().next(), Some(107));
     /// ```
-    pub fn range(&self) -> (u64, u64) {
-        (*(self.range.start()), *(self.range.end()))
+    pub fn iter_emirps<'a>(&'a self, range: RangeInclusive<u64>) -> PrimeResult<impl Iterator<Item = u64> + 'a> {
+        let emirps = self.try_iter(range)?.filter(|&prime| {
+            match reverse_decimal(prime) {
+                Some(reversed) => reversed != prime && miller_rabin(reversed),
+                None => false,
+            }
+        });
+
+        Ok(emirps)
     }
 
-    /// Retrieves the PrimeData offset
+    /// Iterates over the palindromic primes in the given range
     /// 
-    /// PrimeData stores its raw data based on its range. The data starts at ⌊ range.start / 30 ⌋
+    /// A palindromic prime is a prime whose decimal digits read the same backwards, such as 11 or
+    /// 131. Every palindrome with an even number of digits is divisible by 11, so besides 11 itself,
+    /// they all have an odd number of digits.
     /// 
-    /// To learn more, read the [guide](crate::guide::data_structure::_2_prime_data)
+    /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error if
+    /// the given range falls out of the data (self) range.
     /// 
     /// # Examples
     /// 
     /// ```
     /// use prime_data::PrimeData;
-    /// let data = PrimeData::new();
+    /// let data = PrimeData::generate(0..=200);
     /// 
-    /// assert_eq!(data.expand(29..=100).offset(), 0);
-    /// assert_eq!(data.expand(30..=100).offset(), 1);
-    /// assert_eq!(data.expand(59..=100).offset(), 1);
+    /// let palindromes: Vec<u64> = data.iter_palindromic(0..=200).unwrap().collect();
+    /// assert_eq!(palindromes, vec![2, 3, 5, 7, 11, 101, 131, 151, 181, 191]);
     /// ```
-    pub fn offset(&self) -> usize {
-        *(self.range.start()) as usize / 30
+    pub fn iter_palindromic<'a>(&'a self, range: RangeInclusive<u64>) -> PrimeResult<impl Iterator<Item = u64> + 'a> {
+        Ok(self.try_iter(range)?.filter(|&prime| is_decimal_palindrome(prime)))
     }
 
-    /// Tries to verify if the given number is prime
+    /// Counts how many times each gap between consecutive primes happens in the given range
     /// 
-    /// Returns an [OutOfBounds](crate::error::ErrorType::OutOfBounds) error if
-    /// the data range does not contain x.
+    /// Each item is a tuple `(gap, frequency)`, sorted by the gap. Only gaps between two primes in
+    /// the range are counted, so the gap from the last prime in the range to the next one is not.
     /// 
-    /// See [`PrimeData::is_prime`]
-    pub fn try_is_prime(&self, x: u64) -> PrimeResult<bool> {
-        if self.range.contains(&x) && !self.is_empty() {
-
-            if (x == 2) || (x == 3) || (x == 5) { return Ok(true) }
-            if x.divisible_by(30) { return Ok(false) }
-
-            let index = self.data_index_that_contains(x).unwrap();
-            Ok(self.data[index].is_prime((x % 30) as u8))
-        } else {
-            let error = PrimeError {
-                context: ErrorContext { action: ErrorAction::Reading, source: ErrorSource::PrimeData },
-                error: ErrorType::OutOfBounds(x)
-            };
-
-            return Err(error)
+    /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error if
+    /// the given range falls out of the data (self) range.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let data = PrimeData::generate(0..=100);
+    /// let histogram = data.gap_histogram(0..=100).unwrap();
+    /// 
+    /// // 2 -> 3 is the only gap of size 1, and there are 8 twin primes below 100
+    /// assert_eq!(histogram[0], (1, 1));
+    /// assert_eq!(histogram[1], (2, 8));
+    /// assert_eq!(histogram.iter().map(|&(_, frequency)| frequency).sum::<u64>(), 24);
+    /// ```
+    pub fn gap_histogram(&self, range: RangeInclusive<u64>) -> PrimeResult<Vec<(u64, u64)>> {
+        let mut histogram = std::collections::BTreeMap::new();
+        let mut primes = self.try_iter(range)?;
+
+        if let Some(mut previous) = primes.next() {
+            for prime in primes {
+                *histogram.entry(prime - previous).or_insert(0u64) += 1;
+                previous = prime;
+            }
         }
+
+        Ok(histogram.into_iter().collect())
     }
 
-    /// Verifies if the given number is prime
+    /// Collects all primes in the given range into a vector
     /// 
-    /// **Note**: If your data starts below 7, it's heavily recommended to use the
-    /// [`check_primes`](crate::PrimeData::check_prime) method instead. This is because
-    /// this method requires the given parameter to lie within the data range. But the other only
-    /// requires that the data range includes `7..=sqrt(parameter)`.
+    /// The primes are counted before being collected, so the vector is allocated only once, with
+    /// the exact capacity it needs.
     /// 
-    /// See [`PrimeData::try_is_prime`] if you wish to return an error instead of panicking.
+    /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error if
+    /// the given range falls out of the data (self) range.
     /// 
-    /// # Panics
+    /// If you wish to iterate over the primes without collecting them, see [`PrimeData::try_iter`].
     /// 
-    /// Panics if it falls out of the data range
+    /// # Examples
     /// 
     /// ```
     /// use prime_data::PrimeData;
-    /// let data = PrimeData::generate(0..=900);
+    /// let data = PrimeData::generate(0..=1000);
+    /// let primes = data.primes_in_range(472..=491).unwrap();
     /// 
-    /// assert!( data.is_prime(727));
-    /// assert!(!data.is_prime(781));
-    /// ```
+    /// assert_eq!(primes, vec![479, 487, 491]);
+    /// assert_eq!(primes.capacity(), 3);
     /// 
-    /// 907 is a prime number, but it's not in the range (0..=900).
-    /// ```should_panic
-    /// # use prime_data::PrimeData;
-    /// # let data = PrimeData::generate(0..=900);
-    /// assert!(data.is_prime(907));
+    /// assert!(data.primes_in_range(990..=1010).is_err());
     /// ```
-    pub fn is_prime(&self, x: u64) -> bool {
-        self.try_is_prime(x).unwrap()
+    pub fn primes_in_range(&self, range: RangeInclusive<u64>) -> PrimeResult<Vec<u64>> {
+        // the iterator already counted its primes, so they're not counted twice
+        let iter = self.try_iter(range)?;
+
+        let mut primes = Vec::with_capacity(iter.len());
+        primes.extend(iter);
+
+        Ok(primes)
     }
 
-    /// Tries to count the amount of prime numbers in a given range
+    /// Collects all primes in the given range into a vector, in descending order
+    /// 
+    /// [`PrimeIter`] can't iterate backwards, so this simply [collects](PrimeData::primes_in_range)
+    /// the primes and reverses them. Keep in mind that, unlike a reverse iterator, this allocates a
+    /// vector with every prime in the range, even if you only need the last few.
     /// 
     /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error if
     /// the given range falls out of the data (self) range.
     /// 
-    /// See [`PrimeData::count_primes_in_range`].
-    pub fn try_count_primes_in_range(&self, range: RangeInclusive<u64>) -> PrimeResult<u64> {
-        if let Err(missing_range) = self.range.contains_range(&range) {
-            let error = PrimeError {
-                context: ErrorContext { action: ErrorAction::Reading, source: ErrorSource::PrimeData },
-                error: ErrorType::NotEnoughData(missing_range)
-            };
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let data = PrimeData::new();
+    /// 
+    /// assert_eq!(data.primes_desc(0..=30).unwrap(), vec![29, 23, 19, 17, 13, 11, 7, 5, 3, 2]);
+    /// assert!(data.primes_desc(0..=31).is_err());
+    /// ```
+    pub fn primes_desc(&self, range: RangeInclusive<u64>) -> PrimeResult<Vec<u64>> {
+        let mut primes = self.primes_in_range(range)?;
+        primes.reverse();
 
-            return Err(error)
-        }
-
-        if self.is_empty() { return Ok(0) }
-
-        // primedata does not take 2, 3, and 5 into account
-        let missing_primes = [2, 3, 5].iter().filter(|x| range.contains(x)).count() as u64;
-
-        let (start, end) = range.into_inner();
-
-        use cmp::Ordering;
-        match start.cmp(&end) {
-            // start > end implies range.is_empty()
-            Ordering::Greater => { Ok(0) },
-            // start = end implies range is a singular value
-            // fun fact: in maths we call this a degenerate interval
-            Ordering::Equal => { if self.is_prime(start) { Ok(1) } else { Ok(0) } },
-            // most of the time, start < end
-            Ordering::Less => {
-
-                let start_index = self.data_index_that_contains(start).unwrap();
-                let end_index = self.data_index_that_contains(end).unwrap();
-
-                let start_mod = (start % 30) as u8;
-                let end_mod = (end % 30) as u8;
-
-                if start.divisible_by(30) && end.divisible_by(30) {
-                    let end_index = start_index + ((end - start) as usize / 30);
-
-                    let prime_count = self.data[start_index..end_index].iter()
-                    .fold(0u64, |acc, cur| acc + cur.count_primes());
-
-                    Ok(missing_primes + prime_count)
-                } else if start.divisible_by(30) {
-                    let prime_count = self.data[start_index..end_index].iter()
-                    .fold(0u64, |acc, cur| acc + cur.count_primes());
-
-                    let last_primes = self.data[end_index]
-                    .count_primes_in_range(0..=end_mod);
-
-                    Ok(missing_primes + prime_count + last_primes)
-                } else if end.divisible_by(30) {
-                    let first_primes = self.data[start_index]
-                    .count_primes_in_range(start_mod..=30);
-
-                    if start_index == end_index || start + 30 > end {
-                        Ok(missing_primes + first_primes)
-                    } else {
-                        let end_index = if end == *(self.range.end()) { end_index } else { end_index - 1 };
-
-                        let prime_count = self.data[(start_index+1)..=end_index].iter()
-                        .fold(0u64, |acc, cur| acc + cur.count_primes());
-
-                        Ok(missing_primes + first_primes + prime_count)
-                    }
-                } else {
-                    // from here, we know (start % 30 != 0 != end % 30)
-                    if start_index == end_index {
-                        let prime_range = self.data[start_index]
-                        .count_primes_in_range(start_mod..=end_mod);
-
-                        Ok(missing_primes + prime_range)
-                    } else {
-                        let first_primes = self.data[start_index]
-                        .count_primes_in_range(start_mod..=30);
-
-                        let prime_count = self.data[(start_index+1)..end_index].iter()
-                        .fold(0u64, |acc, cur| acc + cur.count_primes());
-
-                        let last_primes = self.data[end_index]
-                        .count_primes_in_range(0..=end_mod);
-
-                        Ok(missing_primes + first_primes + prime_count + last_primes)
-                    }
-                }
-            }
-        }
+        Ok(primes)
     }
 
-    /// Counts the amount of prime numbers in a given range
+    /// Creates a vector with one boolean for every integer in the range, telling if it's prime
     /// 
-    /// Keep in mind that if the range start is greater than the range end, Rust interprets that as an
-    /// empty range, and this function will return zero. 
+    /// The entry at index `i` refers to the number `range.start + i`. Unlike the data itself, this
+    /// includes the primes 2, 3, and 5.
     /// 
-    /// See [`PrimeData::try_count_primes_in_range`] if you wish to return an error instead of panicking.
+    /// **Warning**: This vector takes one byte per integer, while the data takes one byte per 30 of
+    /// them. So create this vector carefully!
+    /// 
+    /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error if
+    /// the given range falls out of the data (self) range.
     /// 
     /// # Examples
     /// 
     /// ```
     /// use prime_data::PrimeData;
-    /// let data = PrimeData::new();
+    /// let data = PrimeData::generate(0..=100);
+    /// let mask = data.primality_mask(0..=100).unwrap();
     /// 
-    /// assert_eq!(data.count_primes_in_range(0..=30), 10);
-    /// assert_eq!(data.count_primes_in_range(2..=7),   4);
-    /// assert_eq!(data.count_primes_in_range(2..=2),   1);
-    /// assert_eq!(data.count_primes_in_range(24..=26), 0);
-    /// assert_eq!(data.count_primes_in_range(30..=0),  0);
+    /// assert_eq!(mask.len(), 101);
+    /// for x in 0..=100 {
+    ///     assert_eq!(mask[x as usize], data.is_prime(x));
+    /// }
+    /// 
+    /// assert_eq!(data.primality_mask(89..=91).unwrap(), vec![true, false, false]);
+    /// assert!(data.primality_mask(90..=110).is_err());
     /// ```
-    pub fn count_primes_in_range(&self, range: RangeInclusive<u64>) -> u64 {
-        self.try_count_primes_in_range(range).unwrap()
+    pub fn primality_mask(&self, range: RangeInclusive<u64>) -> PrimeResult<Vec<bool>> {
+        let (start, end) = range.bounds();
+        let primes = self.try_iter(range)?;
+
+        let length = if start > end { 0 } else { (end - start + 1) as usize };
+        let mut mask = vec![false; length];
+
+        for prime in primes {
+            mask[(prime - start) as usize] = true;
+        }
+
+        Ok(mask)
     }
 
-    /// Counts the amount of prime numbers in the entire data
+    /// Iterates over the raw [prime bytes](crate::PrimeByte) of the data, along with their offsets
     /// 
-    /// If you wish to only count primes within a specific range, see [`PrimeData::count_primes_in_range`].
+    /// Each item is a tuple `(offset, byte)`, such that the byte's k-values `k` correspond to the
+    /// numbers `30 * offset + k`. This is useful if you wish to process the data at byte granularity,
+    /// such as counting or decoding primes in batches.
+    /// 
+    /// The first and last bytes usually also cover numbers outside of the data range. Those bits are
+    /// cleared, so every bit set to one is a prime within the range.
+    /// 
+    /// **Note**: Just like the data itself, the bytes do not store the primes 2, 3, and 5.
     /// 
     /// # Examples
     /// 
     /// ```
     /// use prime_data::PrimeData;
-    /// let below_30   = PrimeData::new();
-    /// let below_100  = below_30.expand(0..=100);
-    /// let below_1000 = below_100.expand(0..=1000);
+    /// let data = PrimeData::generate(50..=1000);
     /// 
-    /// assert_eq!(below_30.count_primes(), 10);
-    /// assert_eq!(below_100.count_primes(), 25);
-    /// assert_eq!(below_1000.count_primes(), 168);
+    /// let primes: Vec<u64> = data.iter_bytes()
+    ///     .flat_map(|(offset, byte)| byte.as_primes(offset))
+    ///     .collect();
+    /// 
+    /// assert_eq!(primes, data.iter_all().collect::<Vec<u64>>());
     /// ```
-    pub fn count_primes(&self) -> u64 {
-        self.count_primes_in_range(self.range.clone())
+    pub fn iter_bytes<'a>(&'a self) -> impl Iterator<Item = (u64, PrimeByte)> + 'a {
+        (0..self.data.len()).map(move |index| self.masked_byte(index))
     }
 
-    /// Verifies if the data is empty.
+    /// Copies the raw bytes of the data
     /// 
-    /// Returns `true` if and only if the the range end is greater than the range start.
+    /// Every byte holds the primality of 8 [k-values](crate::K_VALUES), starting from the data's
+    /// [offset](PrimeData::offset). Unlike [`PrimeData::iter_bytes`], the bits outside of the range
+    /// are left as they are, so these bytes can be given back to
+    /// [`PrimeData::from_range_and_bytes`] along with the same range.
     /// 
     /// # Examples
     /// 
     /// ```
     /// use prime_data::PrimeData;
+    /// let data = PrimeData::generate(0..=60);
     /// 
-    /// assert!(!PrimeData::generate(17..=71).is_empty());
-    /// assert!(!PrimeData::generate(29..=29).is_empty());
-    /// assert!( PrimeData::generate(31..=30).is_empty());
+    /// assert_eq!(data.as_raw_bytes(), vec![0b01111111, 0b11111011]);
     /// ```
-    pub fn is_empty(&self) -> bool {
-        let (start, end) = self.range();
-        start > end // || self.data.len() == 0
+    pub fn as_raw_bytes(&self) -> Vec<u8> {
+        self.data.iter().map(|&byte| byte.into()).collect()
     }
 
-    /// Tries to find the nth prime using the given data
+  // methods for expansion/generation
+
+    /// Tries to expand the current PrimeData into more PrimeData
     /// 
-    /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error in two situations:
+    /// The expanded data will contain all primes in `range`. Only the primes from 7 up to the square
+    /// root of the range end are needed for sieving, so the range may start anywhere, even before the
+    /// data (self) does. Since 2, 3, and 5 are never stored, they're always included when the range
+    /// covers them.
     /// 
-    /// * The data starts anywhere after 7: This function requires that we count all primes up to
-    /// some bound, so we need the range to start at the beginning. Anywhere `<= 7` suffices.
-    /// * The data doesn't have n primes: Naturally, if we want the 1000th prime, we can't retrieve
-    /// it if the data only has 999.
+    /// If the data (self) already contains the whole range, nothing is sieved, and its bytes for
+    /// the range are simply copied.
     /// 
-    /// Returns an [OutOfBounds](crate::error::ErrorType::OutOfBounds) error if `nth` is zero.
+    /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error if
+    /// the data (self) range does not contain the range `7..=sqrt(range.end)`.
     /// 
-    /// See [`Self::nth_prime`]
-    pub fn try_nth_prime(&self, nth: u64) -> PrimeResult<u64> {
+    /// Returns a [TooLarge](crate::error::ErrorType::TooLarge) error if the expanded data would
+    /// take more bytes than the [limit](crate::set_max_data_bytes).
+    /// 
+    /// See [`PrimeData::expand`].
+    pub fn try_expand(&self, range: RangeInclusive<u64>) -> PrimeResult<Self> {
 
-        match nth {
-            0 => {
-                let error = PrimeError {
-                    context: ErrorContext { action: ErrorAction::Reading, source: ErrorSource::PrimeData },
-                    error: ErrorType::OutOfBounds(nth)
-                };
-    
-                return Err(error)
-            },
-            1 => return Ok(2),
-            2 => return Ok(3),
-            3 => return Ok(5),
-            _ => {}
-        }
+        let end_sqrt = range.end().sqrt_floor();
+
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("try_expand", start = *range.start(), end = *range.end()).entered();
 
-        let total_primes = self.count_primes();
+        if !range.is_empty() && self.range.contains_range(&range).is_ok() {
+            let data_start = range.start() / 30 - self.offset() as u64;
+            let data_end = data_start + Self::data_length(&range);
+            let data = self.data[data_start as usize..data_end as usize].to_vec();
+
+            return Ok(Self { data, range })
+        }
 
-        if let Err(missing_range) = self.range.contains_range(&(7..=total_primes)) {
+        if let Err(missing_range) = self.range.contains_range(&(7..=end_sqrt)) {
 
             let error = PrimeError {
-                context: ErrorContext { action: ErrorAction::Reading, source: ErrorSource::PrimeData },
+                context: ErrorContext { action: ErrorAction::Modifying, source: ErrorSource::PrimeData, data_range: Some(self.range.clone()), suggestion: None },
                 error: ErrorType::NotEnoughData(missing_range)
             };
 
             return Err(error)
         }
 
-        let (unchecked_start, unchecked_end) = super::estimate::nth_prime_bounds(nth).into_inner();
-        let start = std::cmp::max(7, unchecked_start);
-        let end = std::cmp::min(*(self.range.end()), unchecked_end);
+        Self::check_data_length(&range, ErrorAction::Modifying)?;
 
-        let offset = 3 + self.count_primes_in_range(7..=start) - (if self.is_prime(start) { 1 } else { 0 });
+        #[cfg(feature = "rayon")]
+        if super::parallel::exceeds_threshold(&range) {
+            return Ok(self.sieve_parallel(range))
+        }
 
-        Ok(self.iter(start..=end).nth((nth - offset - 1) as usize).unwrap())
+        Ok(self.sieve(range))
     }
 
-    /// Retrieves the nth prime number from some data
+    /// Expands the current PrimeData into more PrimeData
     /// 
-    /// If we call "nth prime number" as p(n), we have that p(1) = 2, because 2 is the first prime
-    /// number. p(2) = 3, and so on. Therefore, the "zeroth" prime number is not defined.
+    /// The expanded data will contain all primes in the given range.
     /// 
-    /// See [`Self::try_nth_prime`] if you wish to return an error instead of panicking.
+    /// See [`PrimeData::try_expand`] if you wish to return an error instead of panicking.
     /// 
     /// # Panics
+    ///
+    /// Panics if the given PrimeData does not have enough data to expand into the given range.
     /// 
-    /// Panics in the following situations:
-    /// 
-    /// * `nth` is zero
-    /// * PrimeData (self) starts after 7
-    /// * PrimeData (self) ends before `nth`
+    /// More specifically, if you wish to expand it to some prime data with range (X..=Y), the
+    /// PrimeData (self) must have a range from 7 to √Y or more.
     /// 
     /// # Examples
     /// 
     /// ```
     /// use prime_data::PrimeData;
-    /// let data = PrimeData::generate(7..=105_000);
     /// 
-    /// assert_eq!(data.nth_prime(1), 2);
-    /// assert_eq!(data.nth_prime(4), 7);
-    /// assert_eq!(data.nth_prime(19), 67);
-    /// assert_eq!(data.nth_prime(10001), 104743);
+    /// let starter_data = PrimeData::new();
+    /// let expanded_data = starter_data.expand(59..=90);
+    /// 
+    /// assert_eq!(
+    ///     expanded_data.iter_all().collect::<Vec<u64>>(),
+    ///     vec![59, 61, 67, 71, 73, 79, 83, 89]
+    /// );
     /// ```
-    pub fn nth_prime(&self, nth: u64) -> u64 {
-        self.try_nth_prime(nth).unwrap()
+    pub fn expand(&self, range: RangeInclusive<u64>) -> Self {
+        self.try_expand(range).unwrap()
     }
 
-    /// Tries to verify if the given number is prime
+    /// Sieves all primes in the given range, using the primes in `base`
     /// 
-    /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error if both are true:
+    /// This is the single sieving step that [`PrimeData::generate`] repeats until it reaches the
+    /// range you want, and it's the same as calling [`base.try_expand(range)`](PrimeData::try_expand).
+    /// Since the base is given explicitly, it's handy for testing expansions against some specific
+    /// base. Keep in mind that the base is trusted: if it has wrong data, the result will be wrong too.
     /// 
-    /// * The data range does not include x
-    /// * The data range does not contain the range between 7 and √x
+    /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error if
+    /// the base range does not contain the range between 7 and √end.
     /// 
-    /// See [`Self::check_prime`]
-    pub fn try_check_prime(&self, x: u64) -> PrimeResult<bool> {
-        if self.range.contains(&x) {
-            self.try_is_prime(x)
-        } else {
-
-            let sqrt = x.sqrt_floor();
-
-            if let Err(missing_range) = self.range.contains_range(&(7..=sqrt)) {
-
-                let error = PrimeError {
-                    context: ErrorContext { action: ErrorAction::Reading, source: ErrorSource::PrimeData },
-                    error: ErrorType::NotEnoughData(missing_range)
-                };
-    
-                return Err(error)
-            }
-
-            if (x == 2) || (x == 3) || (x == 5) { return Ok(true) }
-            if x.divisible_by(30) { return Ok(false) }
-
-            for prime in self.iter(7..=sqrt) {
-                if x.divisible_by(prime) { return Ok(false) }
-            }
-
-            Ok(true)
-        }
-    }
-
-    /// Verifies if the given number is prime
+    /// Returns a [TooLarge](crate::error::ErrorType::TooLarge) error if the expanded data would
+    /// take more bytes than the [limit](crate::set_max_data_bytes).
     /// 
-    /// If we have information of all prime numbers between 0 and √x, we can verify if x is prime
-    /// by checking if none of those primes divide x. Therefore, when calling this function, if x
-    /// lies inside the data, it's O(1), but if the data has information about all primes up to √x,
-    /// it's O(n).
+    /// # Examples
     /// 
-    /// See [`Self::try_check_prime`] if you wish to return an error instead of panicking.
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let base = PrimeData::generate(0..=100);
     /// 
-    /// # Panics
+    /// let data = PrimeData::expand_from(&base, 9_000..=10_000).unwrap();
+    /// assert_eq!(data.count_primes(), 112);
     /// 
-    /// Panics if both are true:
+    /// // 101² = 10201 needs the prime 101, which the base does not have
+    /// assert!(PrimeData::expand_from(&base, 9_000..=10_201).is_err());
+    /// ```
+    pub fn expand_from(base: &PrimeData, range: RangeInclusive<u64>) -> PrimeResult<Self> {
+        base.try_expand(range)
+    }
+
+    /// Expands the data in place, until it contains all primes up to `new_end`
     /// 
-    /// * The data range does not include x
-    /// * The data range does not contain the range between 7 and √x
+    /// Unlike [`PrimeData::try_expand`], this doesn't create new data. The bytes for the new numbers
+    /// are appended to the existing ones, and only those new numbers are sieved, using the primes the
+    /// data already has. If the data was created with [`PrimeData::with_capacity`], this may not even
+    /// need to reallocate. If `new_end` is not past the data's end, nothing happens.
+    /// 
+    /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error if
+    /// the data range does not contain the range between 7 and √new_end.
+    /// 
+    /// Returns a [TooLarge](crate::error::ErrorType::TooLarge) error if the expanded data would
+    /// take more bytes than the [limit](crate::set_max_data_bytes).
     /// 
     /// # Examples
     /// 
     /// ```
     /// use prime_data::PrimeData;
+    /// let mut data = PrimeData::with_capacity(0..=10_000);
     /// 
-    /// let data = PrimeData::generate(7..=35);
+    /// data.expand_in_place(100).unwrap();
+    /// data.expand_in_place(10_000).unwrap();
+    /// assert_eq!(data.range(), (0, 10_000));
+    /// assert_eq!(data.count_primes(), 1229);
     /// 
-    /// assert!( data.check_prime(7));  // O(1)
-    /// assert!(!data.check_prime(35)); // O(1)
-    /// assert!( data.check_prime(1123));  // O(n)
-    /// assert!(!data.check_prime(1225));  // O(n)
+    /// // 10_000² is fine, but 10_007² needs the prime 10_007
+    /// assert!(data.expand_in_place(10_007 * 10_007).is_err());
     /// ```
-    pub fn check_prime(&self, x: u64) -> bool {
-        self.try_check_prime(x).unwrap()
-    }
+    pub fn expand_in_place(&mut self, new_end: u64) -> PrimeResult<()> {
+        let (start, end) = self.range();
+        if new_end <= end { return Ok(()) }
 
-    /// Tries to factorize the given number into prime factors.
-    /// 
-    /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error if
-    /// the data (self) range does not contain the range `2..=sqrt(x)`.
-    /// 
-    /// See [`Self::factorize`]
-    #[cfg(feature = "factors")]
-    pub fn try_factorize(&self, x: u64) -> PrimeResult<super::Factorization> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("expand_in_place", start, end = new_end).entered();
 
-        let mut number = x;
-        let sqrt = x.sqrt_floor();
+        let end_sqrt = new_end.sqrt_floor();
 
-        if let Err(missing_range) = self.range.contains_range(&(2..=sqrt)) {
+        if let Err(missing_range) = self.range.contains_range(&(7..=end_sqrt)) {
             let error = PrimeError {
-                context: ErrorContext { action: ErrorAction::Reading, source: ErrorSource::PrimeData },
+                context: ErrorContext { action: ErrorAction::Modifying, source: ErrorSource::PrimeData, data_range: Some(self.range.clone()), suggestion: None },
                 error: ErrorType::NotEnoughData(missing_range)
             };
 
             return Err(error)
         }
 
-        let mut factorization = super::Factorization::new();
+        Self::check_data_length(&(start..=new_end), ErrorAction::Modifying)?;
 
-        for prime in self.iter(2..=sqrt) {
-            while number % prime == 0 {
-                let other_factor = number / prime;
-                factorization.add_factor(prime);
-                number /= prime;
-            }
-        }
+        // the primes come from the data we're about to modify, so they're read beforehand
+        let primes = self.iter(7..=end_sqrt).collect::<Vec<u64>>();
 
-        if number > 1 {
-            factorization.add_factor(number);
+        let new_numbers = cmp::max(start, end + 1)..=new_end;
+        self.data.resize(Self::data_length(&(start..=new_end)) as usize, PrimeByte::new());
+        self.range = start..=new_end;
+
+        // We want 1 to be set as nonprime by default
+        if new_numbers.contains(&1) {
+            self.set_nonprime(1).unwrap();
         }
 
-        Ok(factorization)
+        self.cross_out_multiples(primes.into_iter(), new_numbers);
+
+        Ok(())
     }
 
-    /// Factorizes the given number into prime factors.
-    /// 
-    /// See [`Self::try_factorize`] if you wish to return an error instead of panicking.
+    /// Tries to split the data into two contiguous pieces of data at `n`
     /// 
-    /// # Panics
+    /// The first piece will have the range `start..=n`, and the second one `n..=end`. Therefore, `n`
+    /// belongs to both of them. If you wish to count primes across both pieces, keep in mind that
+    /// if `n` is prime, it'll be counted twice.
     /// 
-    /// Panics if the data (self) range does not contain the range `2..=sqrt(x)`.
+    /// Returns an [OutOfBounds](crate::error::ErrorType::OutOfBounds) error if
+    /// the data range does not contain n.
     /// 
     /// # Examples
     /// 
-    /// This function returns a [Factorization](super::Factorization) struct. Read
-    /// to the struct's documentation for its methods and examples.
-    #[cfg(feature = "factors")]
-    pub fn factorize(&self, x: u64) -> super::Factorization {
-        self.try_factorize(x).unwrap()
-    }
-}
-
-// private methods
-impl PrimeData {
-    // Creates "empty" data, with all bits set to one.
-    // Should only be called by expansion functions.
-    fn create_empty(range: RangeInclusive<u64>) -> Self {
-        let data_start = (*range.start()).div_floor(30);
-        let data_end   = (*range.end()).div_ceil(30);
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let data = PrimeData::generate(0..=1000);
+    /// let (left, right) = data.split_at(509).unwrap();
+    /// 
+    /// assert_eq!(left.range(), (0, 509));
+    /// assert_eq!(right.range(), (509, 1000));
+    /// 
+    /// // 509 is prime, so it's in both pieces
+    /// assert_eq!(left.count_primes() + right.count_primes() - 1, data.count_primes());
+    /// 
+    /// assert!(data.split_at(1001).is_err());
+    /// ```
+    pub fn split_at(&self, n: u64) -> PrimeResult<(Self, Self)> {
+        if !self.range.contains(&n) {
+            let error = PrimeError {
+                context: ErrorContext { action: ErrorAction::Reading, source: ErrorSource::PrimeData, data_range: Some(self.range.clone()), suggestion: None },
+                error: ErrorType::OutOfBounds(n)
+            };
 
-        if data_start >= data_end {
-            return Self { data: vec![], range }
+            return Err(error)
         }
 
-        let data_length = (data_end - data_start) as usize;
-        let mut data = vec![PrimeByte::new(); data_length];
+        let (start, end) = self.range();
+        let offset = self.offset();
+        let split_end = cmp::min(n.div_ceil(30) as usize - offset, self.data.len());
+        let split_start = cmp::min(n as usize / 30 - offset, split_end);
 
-        // We want 1 to be set as nonprime by default
-        if data_start == 0 {
-            data[0].set_nonprime(1).unwrap();
+        let left = Self { data: self.data[..split_end].to_vec(), range: start..=n };
+        let right = Self { data: self.data[split_start..].to_vec(), range: n..=end };
+
+        Ok((left, right))
+    }
+
+    /// Merges many pieces of data into a single one
+    /// 
+    /// The pieces are sorted by their range, so they can be given in any order. They may overlap,
+    /// like the pieces created by [`PrimeData::split_at`], but there can't be any gaps between them.
+    /// All bytes are copied into a single vector, allocated only once, so this is way faster than
+    /// merging pieces two at a time. If there are no (non-empty) pieces, the merged data is empty.
+    /// 
+    /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error with the first gap
+    /// between the pieces, if there is one.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let pieces = vec![
+    ///     PrimeData::generate(500..=1000),
+    ///     PrimeData::generate(0..=100),
+    ///     PrimeData::generate(101..=499),
+    /// ];
+    /// 
+    /// let merged = PrimeData::merge_all(pieces).unwrap();
+    /// assert_eq!(merged.range(), (0, 1000));
+    /// assert_eq!(merged.count_primes(), 168);
+    /// 
+    /// let pieces = vec![PrimeData::generate(0..=100), PrimeData::generate(102..=200)];
+    /// assert!(PrimeData::merge_all(pieces).is_err());
+    /// ```
+    pub fn merge_all(mut datasets: Vec<PrimeData>) -> PrimeResult<Self> {
+        datasets.retain(|data| !data.is_empty());
+        datasets.sort_by(|a, b| a.range_cmp(b));
+
+        let (start, mut end) = match datasets.first() {
+            Some(first) => first.range(),
+            None => return Ok(Self::create_empty(RangeInclusive::new(1, 0))),
+        };
+
+        for data in datasets.iter().skip(1) {
+            let (data_start, data_end) = data.range();
+
+            if data_start > end.saturating_add(1) {
+                let error = PrimeError {
+                    context: ErrorContext { action: ErrorAction::Modifying, source: ErrorSource::PrimeData, data_range: None, suggestion: None },
+                    error: ErrorType::NotEnoughData((end + 1)..=(data_start - 1))
+                };
+
+                return Err(error)
+            }
+
+            end = cmp::max(end, data_end);
+        }
+
+        let offset = start / 30;
+        let mut merged: Vec<PrimeByte> = Vec::with_capacity(Self::data_length(&(start..=end)) as usize);
+        let mut covered: Option<u64> = None;
+
+        for data in datasets.iter() {
+            let (data_start, data_end) = data.range();
+
+            // the first number this piece has that the previous ones don't
+            let from = match covered {
+                None => data_start,
+                Some(covered) if covered >= data_end => continue,
+                Some(covered) => covered + 1,
+            };
+
+            let mut index = (from / 30) as usize - data.offset();
+
+            // the previous piece may have ended in the middle of the byte that contains `from`
+            if from / 30 < offset + merged.len() as u64 {
+                if let (Some(last), Some(&byte)) = (merged.last_mut(), data.data.get(index)) {
+                    let position = K_VALUES.partition_point(|&k_value| (k_value as u64) < from % 30);
+                    last.overwrite_at(byte, position as u8);
+                }
+                index += 1;
+            }
+
+            if let Some(bytes) = data.data.get(index..) {
+                merged.extend_from_slice(bytes);
+            }
+            covered = Some(data_end);
+        }
+
+        Ok(Self { data: merged, range: start..=end })
+    }
+
+    /// Sets the given number as non-prime/composite
+    /// 
+    /// This function is meant for advanced users building their own sieves on top of PrimeData.
+    /// **It does not verify if the given number is actually composite**. If you set a prime as
+    /// non-prime, the data will simply be wrong from there on, and every other method will happily
+    /// trust it.
+    /// 
+    /// If the number was already set to non-prime, returns false. Otherwise, returns true.
+    /// 
+    /// Returns an [OutOfBounds](crate::error::ErrorType::OutOfBounds) error if
+    /// the data range does not contain n, or if n is not coprime with 30.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let mut data = PrimeData::generate(0..=100);
+    /// 
+    /// // 91 = 7 * 13 is already composite
+    /// assert!(!data.set_nonprime_unchecked(91).unwrap());
+    /// 
+    /// // 97 is prime, but we can still (wrongly) mark it as composite
+    /// assert!(data.is_prime(97));
+    /// assert!(data.set_nonprime_unchecked(97).unwrap());
+    /// assert!(!data.is_prime(97));
+    /// assert_eq!(data.count_primes(), 24);
+    /// 
+    /// assert!(data.set_nonprime_unchecked(98).is_err());
+    /// assert!(data.set_nonprime_unchecked(101).is_err());
+    /// ```
+    pub fn set_nonprime_unchecked(&mut self, n: u64) -> PrimeResult<bool> {
+        self.set_nonprime(n)
+    }
+
+  // general methods
+
+    /// Destructures the PrimeData range into (start, end)
+    /// 
+    /// **Note**: The range is inclusive.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let expansion = PrimeData::generate(173..=244);
+    /// assert_eq!(expansion.range(), (173, 244));
+    /// ```
+    pub fn range(&self) -> (u64, u64) {
+        (*(self.range.start()), *(self.range.end()))
+    }
+
+    /// Compares the ranges of two pieces of data, first by their start, then by their end
+    /// 
+    /// This is useful for sorting a collection of data, for example, before chaining their
+    /// iterators together.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// use std::cmp::Ordering;
+    /// 
+    /// let mut pieces = vec![
+    ///     PrimeData::generate(100..=200),
+    ///     PrimeData::generate(0..=100),
+    ///     PrimeData::generate(100..=150),
+    /// ];
+    /// pieces.sort_by(|a, b| a.range_cmp(b));
+    /// 
+    /// assert_eq!(pieces[0].range(), (0, 100));
+    /// assert_eq!(pieces[1].range(), (100, 150));
+    /// assert_eq!(pieces[2].range(), (100, 200));
+    /// assert_eq!(pieces[0].range_cmp(&PrimeData::generate(0..=100)), Ordering::Equal);
+    /// ```
+    pub fn range_cmp(&self, other: &PrimeData) -> cmp::Ordering {
+        self.range().cmp(&other.range())
+    }
+
+    /// Verifies if the data covers more integers than some other data
+    /// 
+    /// Only the amount of integers in each range matters, not where they are. Empty data covers
+    /// no integers at all.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let small = PrimeData::generate(0..=100);
+    /// let big = PrimeData::generate(1000..=2000);
+    /// 
+    /// assert!( big.covers_more_than(&small));
+    /// assert!(!small.covers_more_than(&big));
+    /// assert!(!small.covers_more_than(&PrimeData::generate(200..=300)));
+    /// ```
+    pub fn covers_more_than(&self, other: &PrimeData) -> bool {
+        self.integer_count() > other.integer_count()
+    }
+
+    /// Verifies if two pieces of data agree on the primality of every number within both of them
+    /// 
+    /// The ranges don't need to be equal, since this only compares the overlap between them, one
+    /// byte at a time. If they don't overlap, there's nothing to disagree
+    /// on, so it returns true.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let data = PrimeData::generate(0..=100);
+    /// let mut other = PrimeData::generate(50..=150);
+    /// 
+    /// assert!(data.agrees_with(&other));
+    /// 
+    /// // 149 is not within 0..=100, so they still agree
+    /// other.set_nonprime_unchecked(149).unwrap();
+    /// assert!(data.agrees_with(&other));
+    /// 
+    /// other.set_nonprime_unchecked(97).unwrap();
+    /// assert!(!data.agrees_with(&other));
+    /// ```
+    pub fn agrees_with(&self, other: &PrimeData) -> bool {
+        let (start, end) = match self.range.overlap(&other.range) {
+            Some(overlap) => overlap.bounds(),
+            None => return true,
+        };
+
+        (start / 30..=end / 30).all(|offset| {
+            let lower = if offset == start / 30 { (start % 30) as u8 } else { 0 };
+            let upper = if offset == end / 30 { (end % 30) as u8 } else { 30 };
+
+            // a range ending at a multiple of 30 has no byte for it, but that byte wouldn't hold
+            // any number from the overlap anyway
+            let self_byte = self.data.get(offset as usize - self.offset());
+            let other_byte = other.data.get(offset as usize - other.offset());
+
+            match (self_byte, other_byte) {
+                (Some(self_byte), Some(&other_byte)) => self_byte.matches_in_range(other_byte, lower..=upper),
+                _ => true,
+            }
+        })
+    }
+
+    /// Retrieves the PrimeData offset
+    /// 
+    /// PrimeData stores its raw data based on its range. The data starts at ⌊ range.start / 30 ⌋
+    /// 
+    /// To learn more, read the [guide](crate::guide::data_structure::_2_prime_data)
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let data = PrimeData::new();
+    /// 
+    /// assert_eq!(data.expand(29..=100).offset(), 0);
+    /// assert_eq!(data.expand(30..=100).offset(), 1);
+    /// assert_eq!(data.expand(59..=100).offset(), 1);
+    /// ```
+    pub fn offset(&self) -> usize {
+        *(self.range.start()) as usize / 30
+    }
+
+    /// Tries to verify if the given number is prime
+    /// 
+    /// Returns an [OutOfBounds](crate::error::ErrorType::OutOfBounds) error if
+    /// the data range does not contain x.
+    /// 
+    /// See [`PrimeData::is_prime`]
+    pub fn try_is_prime(&self, x: u64) -> PrimeResult<bool> {
+        if self.range.contains(&x) && !self.is_empty() {
+
+            if (x == 2) || (x == 3) || (x == 5) { return Ok(true) }
+            if x.divisible_by(30) { return Ok(false) }
+
+            let index = self.data_index_that_contains(x).unwrap();
+            Ok(self.data[index].is_prime((x % 30) as u8))
+        } else {
+            // x may still be verified by dividing it by the primes up to its square root
+            let suggestion = if !self.is_empty() && self.range.contains_range(&(7..=x.sqrt_floor())).is_ok() {
+                Some("The number is out of the data range, but PrimeData::check_prime can verify it with the primes up to its square root")
+            } else {
+                None
+            };
+
+            let error = PrimeError {
+                context: ErrorContext { action: ErrorAction::Reading, source: ErrorSource::PrimeData, data_range: Some(self.range.clone()), suggestion },
+                error: ErrorType::OutOfBounds(x)
+            };
+
+            Err(error)
+        }
+    }
+
+    /// Verifies if the given number is prime
+    /// 
+    /// **Note**: If your data starts below 7, it's heavily recommended to use the
+    /// [`check_primes`](crate::PrimeData::check_prime) method instead. This is because
+    /// this method requires the given parameter to lie within the data range. But the other only
+    /// requires that the data range includes `7..=sqrt(parameter)`.
+    /// 
+    /// See [`PrimeData::try_is_prime`] if you wish to return an error instead of panicking.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if it falls out of the data range
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let data = PrimeData::generate(0..=900);
+    /// 
+    /// assert!( data.is_prime(727));
+    /// assert!(!data.is_prime(781));
+    /// ```
+    /// 
+    /// 907 is a prime number, but it's not in the range (0..=900).
+    /// ```should_panic
+    /// # use prime_data::PrimeData;
+    /// # let data = PrimeData::generate(0..=900);
+    /// assert!(data.is_prime(907));
+    /// ```
+    pub fn is_prime(&self, x: u64) -> bool {
+        self.try_is_prime(x).unwrap()
+    }
+
+    /// Verifies if the given number is prime, taking it by reference
+    /// 
+    /// This is exactly the same as [`PrimeData::is_prime`], but it reads better when filtering
+    /// iterators over references, which would otherwise need to be dereferenced.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if it falls out of the data range
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let data = PrimeData::generate(0..=100);
+    /// let numbers = [1, 2, 9, 15, 31, 57, 89, 91];
+    /// 
+    /// let primes: Vec<&u64> = numbers.iter().filter(|x| data.is_prime_ref(x)).collect();
+    /// assert_eq!(primes, vec![&2, &31, &89]);
+    /// ```
+    pub fn is_prime_ref(&self, x: &u64) -> bool {
+        self.is_prime(*x)
+    }
+
+    /// Tries to verify if the given number is prime, consulting some filter first
+    /// 
+    /// If `filter` returns `Some` verdict, it's returned immediately, without touching the data.
+    /// Otherwise, this is the same as [`PrimeData::try_is_prime`]. This lets you plug in shortcuts
+    /// you know of, such as numbers of a form that can never be prime, and only read the data for
+    /// the rest. The filter's verdict is trusted as is, so a wrong shortcut gives a wrong answer.
+    /// 
+    /// Returns an [OutOfBounds](crate::error::ErrorType::OutOfBounds) error if the filter
+    /// returns `None` and the data range does not contain x.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let data = PrimeData::generate(0..=100);
+    /// 
+    /// // 2^n + 1 can only be prime when n is a power of two
+    /// let fermat = |x: u64| if (x - 1).is_power_of_two() && !(x - 1).trailing_zeros().is_power_of_two() {
+    ///     Some(false)
+    /// } else {
+    ///     None
+    /// };
+    /// 
+    /// assert!( data.is_prime_with_filter(17, fermat).unwrap());
+    /// // 129 is out of the data range, but the filter answers for it
+    /// assert!(!data.is_prime_with_filter(129, fermat).unwrap());
+    /// assert!(data.is_prime_with_filter(257, fermat).is_err());
+    /// ```
+    pub fn is_prime_with_filter<F: Fn(u64) -> Option<bool>>(&self, x: u64, filter: F) -> PrimeResult<bool> {
+        match filter(x) {
+            Some(verdict) => Ok(verdict),
+            None => self.try_is_prime(x),
+        }
+    }
+
+    /// Verifies if the given number is a prime that this data knows about
+    /// 
+    /// Unlike the other primality methods, this one never fails. If `p` falls out of the data
+    /// range, it simply returns false. To sum them up:
+    /// 
+    /// * [`PrimeData::is_prime`]: Only answers for numbers in the data range, panicking otherwise.
+    /// * [`PrimeData::check_prime`]: Answers for numbers in the data range, or beyond it, as long
+    ///   as the data contains the range between 7 and √x. Panics otherwise.
+    /// * [`PrimeData::contains_prime`]: Answers if p is in the data range *and* is prime. Returns
+    ///   false otherwise.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let data = PrimeData::generate(100..=200);
+    /// 
+    /// assert!( data.contains_prime(101));
+    /// assert!(!data.contains_prime(111));
+    /// // 97 is prime, but it's not in the data range
+    /// assert!(!data.contains_prime(97));
+    /// assert!(!data.contains_prime(211));
+    /// ```
+    pub fn contains_prime(&self, p: u64) -> bool {
+        self.try_is_prime(p).unwrap_or(false)
+    }
+
+    /// Tries to count the amount of prime numbers in a given range
+    /// 
+    /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error if
+    /// the given range falls out of the data (self) range.
+    /// 
+    /// See [`PrimeData::count_primes_in_range`].
+    pub fn try_count_primes_in_range(&self, range: RangeInclusive<u64>) -> PrimeResult<u64> {
+        self.try_count_primes_in_range_ref(&range)
+    }
+
+    /// Tries to count the amount of prime numbers in a given range, taking it by reference
+    /// 
+    /// This is exactly the same as [`PrimeData::try_count_primes_in_range`], but it lets you keep
+    /// using the range afterwards without cloning it.
+    pub fn try_count_primes_in_range_ref(&self, range: &RangeInclusive<u64>) -> PrimeResult<u64> {
+        if let Err(missing_range) = self.range.contains_range(range) {
+            let error = PrimeError {
+                context: ErrorContext { action: ErrorAction::Reading, source: ErrorSource::PrimeData, data_range: Some(self.range.clone()), suggestion: None },
+                error: ErrorType::NotEnoughData(missing_range)
+            };
+
+            return Err(error)
+        }
+
+        if self.is_empty() { return Ok(0) }
+
+        // primedata does not take 2, 3, and 5 into account
+        let missing_primes = [2, 3, 5].iter().filter(|x| range.contains(x)).count() as u64;
+
+        // if there are no numbers coprime with 30 in the range, there's no need to read the data
+        if CoprimeIter::new(range.clone()).next().is_none() { return Ok(missing_primes) }
+
+        let (first_index, last_index, first_bit, last_bit) = self.byte_span(range);
+
+        let prime_count = if first_index == last_index {
+            self.data[first_index].count_primes_in_range(first_bit..=last_bit)
+        } else {
+            let first_primes = self.data[first_index].count_primes_in_range(first_bit..=30);
+            let last_primes = self.data[last_index].count_primes_in_range(0..=last_bit);
+
+            let prime_count = self.data[(first_index + 1)..last_index].iter()
+            .fold(0u64, |acc, cur| acc + cur.count_primes());
+
+            first_primes + prime_count + last_primes
+        };
+
+        Ok(missing_primes + prime_count)
+    }
+
+    /// Counts the amount of prime numbers in a given range
+    /// 
+    /// Keep in mind that if the range start is greater than the range end, Rust interprets that as an
+    /// empty range, and this function will return zero. 
+    /// 
+    /// See [`PrimeData::try_count_primes_in_range`] if you wish to return an error instead of panicking.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let data = PrimeData::new();
+    /// 
+    /// assert_eq!(data.count_primes_in_range(0..=30), 10);
+    /// assert_eq!(data.count_primes_in_range(2..=7),   4);
+    /// assert_eq!(data.count_primes_in_range(2..=2),   1);
+    /// assert_eq!(data.count_primes_in_range(24..=26), 0);
+    /// assert_eq!(data.count_primes_in_range(30..=0),  0);
+    /// ```
+    pub fn count_primes_in_range(&self, range: RangeInclusive<u64>) -> u64 {
+        self.try_count_primes_in_range(range).unwrap()
+    }
+
+    /// Counts the amount of prime numbers in a given range, taking it by reference
+    /// 
+    /// This is exactly the same as [`PrimeData::count_primes_in_range`], but it lets you keep
+    /// using the range afterwards without cloning it.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the given range falls out of the data range
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let data = PrimeData::generate(0..=1000);
+    /// let range = 100..=200;
+    /// 
+    /// assert_eq!(data.count_primes_in_range_ref(&range), 21);
+    /// assert_eq!(data.iter(range).count(), 21);
+    /// ```
+    pub fn count_primes_in_range_ref(&self, range: &RangeInclusive<u64>) -> u64 {
+        self.try_count_primes_in_range_ref(range).unwrap()
+    }
+
+    /// Counts the amount of prime numbers in the entire data
+    /// 
+    /// If you wish to only count primes within a specific range, see [`PrimeData::count_primes_in_range`].
+    /// 
+    /// **Note**: The count can never overflow a [`u64`]. Each byte holds at most 8 primes, and there
+    /// can't be more than [`isize::MAX`] bytes, so even with 2, 3, and 5, the count stays way below
+    /// [`u64::MAX`]. You don't need to check for overflows when adding up counts of a few pieces of data.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let below_30   = PrimeData::new();
+    /// let below_100  = below_30.expand(0..=100);
+    /// let below_1000 = below_100.expand(0..=1000);
+    /// 
+    /// assert_eq!(below_30.count_primes(), 10);
+    /// assert_eq!(below_100.count_primes(), 25);
+    /// assert_eq!(below_1000.count_primes(), 168);
+    /// ```
+    pub fn count_primes(&self) -> u64 {
+        if self.is_empty() { return 0 }
+
+        let (start, end) = self.range();
+
+        // primedata does not take 2, 3, and 5 into account
+        let small_primes = [2, 3, 5].iter().filter(|&&p| start <= p && p <= end).count() as u64;
+
+        // only the first and last bytes may hold numbers outside of the range
+        let byte_primes = match self.data.len() {
+            0 => 0,
+            1 => self.masked_byte(0).1.count_primes(),
+            length => {
+                let interior: u64 = self.data[1..(length - 1)].iter().map(|byte| byte.count_primes()).sum();
+                self.masked_byte(0).1.count_primes() + interior + self.masked_byte(length - 1).1.count_primes()
+            }
+        };
+
+        small_primes + byte_primes
+    }
+
+    /// Counts the amount of prime numbers between every pair of consecutive boundaries
+    /// 
+    /// The count at index `i` is the amount of primes `p` such that `boundaries[i] < p <= boundaries[i+1]`,
+    /// which is the same as π(boundaries\[i+1\]) - π(boundaries\[i\]). Therefore, the first boundary
+    /// itself is never counted. If there are less than two boundaries, the vector is empty.
+    /// 
+    /// Unlike calling [`PrimeData::count_primes_in_range`] for every bucket, this walks through
+    /// the data only once, so it's handy for building histograms.
+    /// 
+    /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error if the data range
+    /// does not contain the range between the first and last boundaries.
+    /// 
+    /// Returns an [OutOfBounds](crate::error::ErrorType::OutOfBounds) error with the first boundary
+    /// that is smaller than the one before it, if they're not sorted.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let data = PrimeData::generate(0..=1000);
+    /// 
+    /// let counts = data.count_primes_buckets(&[0, 10, 100, 1000]).unwrap();
+    /// assert_eq!(counts, vec![4, 21, 143]);
+    /// assert_eq!(counts.iter().sum::<u64>(), data.count_primes());
+    /// 
+    /// assert!(data.count_primes_buckets(&[0, 100, 50]).is_err());
+    /// assert!(data.count_primes_buckets(&[500, 1500]).is_err());
+    /// ```
+    pub fn count_primes_buckets(&self, boundaries: &[u64]) -> PrimeResult<Vec<u64>> {
+        if let Some(pair) = boundaries.windows(2).find(|pair| pair[0] > pair[1]) {
+            let error = PrimeError {
+                context: ErrorContext { action: ErrorAction::Reading, source: ErrorSource::PrimeData, data_range: Some(self.range.clone()), suggestion: None },
+                error: ErrorType::OutOfBounds(pair[1])
+            };
+
+            return Err(error)
+        }
+
+        let (first, last) = match boundaries {
+            [first, .., last] => (*first, *last),
+            _ => return Ok(vec![]),
+        };
+
+        if let Err(missing_range) = self.range.contains_range(&(first..=last)) {
+            let error = PrimeError {
+                context: ErrorContext { action: ErrorAction::Reading, source: ErrorSource::PrimeData, data_range: Some(self.range.clone()), suggestion: None },
+                error: ErrorType::NotEnoughData(missing_range)
+            };
+
+            return Err(error)
+        }
+
+        let mut counts = vec![0u64; boundaries.len() - 1];
+
+        // primedata does not take 2, 3, and 5 into account
+        for prime in [2, 3, 5] {
+            let below = boundaries.partition_point(|&boundary| boundary < prime);
+            if (1..=counts.len()).contains(&below) {
+                counts[below - 1] += 1;
+            }
+        }
+
+        let mut bucket = 0;
+        for (offset, byte) in self.iter_bytes() {
+            let byte_start = 30 * offset;
+            if byte_start > last { break }
+
+            while bucket < counts.len() {
+                let (lower, upper) = (boundaries[bucket], boundaries[bucket + 1]);
+
+                // buckets that end before this byte, or that are empty, have nothing to count here
+                if upper < byte_start || lower == upper {
+                    bucket += 1;
+                    continue;
+                }
+
+                let start = cmp::min((lower + 1).saturating_sub(byte_start), 30) as u8;
+                let end = cmp::min(upper - byte_start, 30) as u8;
+                counts[bucket] += byte.popcount_in_range(start..=end) as u64;
+
+                // if the bucket goes past this byte, the next byte continues counting it
+                if upper >= byte_start + 29 { break }
+                bucket += 1;
+            }
+        }
+
+        Ok(counts)
+    }
+
+    /// Verifies if the data is empty.
+    /// 
+    /// Returns `true` if and only if the the range end is greater than the range start.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// 
+    /// assert!(!PrimeData::generate(17..=71).is_empty());
+    /// assert!(!PrimeData::generate(29..=29).is_empty());
+    /// assert!( PrimeData::generate(31..=30).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        let (start, end) = self.range();
+        start > end // || self.data.len() == 0
+    }
+
+    /// Summarizes the data into its range, byte count, prime count, density and memory usage
+    /// 
+    /// The density is the amount of primes divided by the amount of integers in the range. For
+    /// empty data, it's zero.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let stats = PrimeData::generate(0..=1000).stats();
+    /// 
+    /// assert_eq!(stats.range, (0, 1000));
+    /// assert_eq!(stats.prime_count, 168);
+    /// assert_eq!(stats.byte_count, 34);
+    /// println!("{}", stats);
+    /// ```
+    pub fn stats(&self) -> PrimeStats {
+        let (start, end) = self.range();
+        let prime_count = self.count_primes();
+
+        let density = if self.is_empty() {
+            0.0
+        } else {
+            prime_count as f64 / (end - start + 1) as f64
+        };
+
+        PrimeStats {
+            range: (start, end),
+            byte_count: self.data.len(),
+            prime_count,
+            density,
+            memory: self.data.len() * std::mem::size_of::<PrimeByte>(),
+        }
+    }
+
+    /// Tries to find the nth prime using the given data
+    /// 
+    /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error in two situations:
+    /// 
+    /// * The data starts anywhere after 7: This function requires that we count all primes up to
+    ///   some bound, so we need the range to start at the beginning. Anywhere `<= 7` suffices.
+    /// * The data doesn't have n primes: Naturally, if we want the 1000th prime, we can't retrieve
+    ///   it if the data only has 999.
+    /// 
+    /// Returns an [OutOfBounds](crate::error::ErrorType::OutOfBounds) error if `nth` is zero.
+    /// 
+    /// See [`Self::nth_prime`]
+    pub fn try_nth_prime(&self, nth: u64) -> PrimeResult<u64> {
+
+        match nth {
+            0 => {
+                let error = PrimeError {
+                    context: ErrorContext { action: ErrorAction::Reading, source: ErrorSource::PrimeData, data_range: Some(self.range.clone()), suggestion: None },
+                    error: ErrorType::OutOfBounds(nth)
+                };
+    
+                return Err(error)
+            },
+            1 => return Ok(2),
+            2 => return Ok(3),
+            3 => return Ok(5),
+            _ => {}
+        }
+
+        let (start, end) = self.range();
+
+        // below the 10000th prime (104729), the bounds are the whole window up to it anyway, so
+        // it's cheaper to read the primes one by one than to count them first
+        if nth < 10_000 && start <= 7 && end >= 7 {
+            let mut primes = self.iter(7..=cmp::min(end, 104_729));
+            #[cfg(feature = "tracing")]
+            let total = primes.len();
+
+            if let Some(prime) = primes.nth((nth - 4) as usize) {
+                #[cfg(feature = "tracing")]
+                tracing::trace!(steps = (total - primes.len()) as u64, "stepped through primes");
+
+                return Ok(prime)
+            }
+        }
+
+        let missing_range = if start > 7 {
+            Some(7..=(start - 1))
+        } else if 3 + self.count_primes_in_range(7..=end) < nth {
+            // the nth prime lies after the data, somewhere within its bounds
+            let (_, upper_bound) = super::estimate::nth_prime_bounds(nth).into_inner();
+            Some(end.saturating_add(1)..=cmp::max(upper_bound, end.saturating_add(1)))
+        } else {
+            None
+        };
+
+        if let Some(missing_range) = missing_range {
+            let error = PrimeError {
+                context: ErrorContext { action: ErrorAction::Reading, source: ErrorSource::PrimeData, data_range: Some(self.range.clone()), suggestion: None },
+                error: ErrorType::NotEnoughData(missing_range)
+            };
+
+            return Err(error)
+        }
+
+        let (unchecked_start, unchecked_end) = super::estimate::nth_prime_bounds(nth).into_inner();
+        let start = std::cmp::max(7, unchecked_start);
+        let end = std::cmp::min(*(self.range.end()), unchecked_end);
+
+        let offset = 3 + self.count_primes_in_range(7..=start) - (if self.is_prime(start) { 1 } else { 0 });
+
+        let mut primes = self.iter(start..=end);
+        #[cfg(feature = "tracing")]
+        let total = primes.len();
+
+        let prime = primes.nth((nth - offset - 1) as usize).unwrap();
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(steps = (total - primes.len()) as u64, "stepped through primes");
+
+        Ok(prime)
+    }
+
+    /// Retrieves the nth prime number from some data
+    /// 
+    /// If we call "nth prime number" as p(n), we have that p(1) = 2, because 2 is the first prime
+    /// number. p(2) = 3, and so on. Therefore, the "zeroth" prime number is not defined.
+    /// 
+    /// See [`Self::try_nth_prime`] if you wish to return an error instead of panicking.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics in the following situations:
+    /// 
+    /// * `nth` is zero
+    /// * PrimeData (self) starts after 7
+    /// * PrimeData (self) ends before `nth`
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let data = PrimeData::generate(7..=105_000);
+    /// 
+    /// assert_eq!(data.nth_prime(1), 2);
+    /// assert_eq!(data.nth_prime(4), 7);
+    /// assert_eq!(data.nth_prime(19), 67);
+    /// assert_eq!(data.nth_prime(10001), 104743);
+    /// ```
+    pub fn nth_prime(&self, nth: u64) -> u64 {
+        self.try_nth_prime(nth).unwrap()
+    }
+
+    /// Retrieves the nth prime number from some data, if it can be found
+    /// 
+    /// This is the same as [`Self::try_nth_prime`], but returns `None` in every situation where
+    /// that would return an error, which is handy in functions that return an [`Option`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let data = PrimeData::generate(0..=100);
+    /// 
+    /// assert_eq!(data.checked_nth_prime(25), Some(97));
+    /// assert_eq!(data.try_nth_prime(25).unwrap(), 97);
+    /// 
+    /// // the 26th prime is 101, which the data doesn't reach
+    /// assert_eq!(data.checked_nth_prime(26), None);
+    /// assert!(data.try_nth_prime(26).is_err());
+    /// 
+    /// assert_eq!(data.checked_nth_prime(0), None);
+    /// ```
+    pub fn checked_nth_prime(&self, nth: u64) -> Option<u64> {
+        self.try_nth_prime(nth).ok()
+    }
+
+    /// Retrieves the nth prime number, jumping straight to the byte that holds it
+    /// 
+    /// This gives the same results as [`PrimeData::try_nth_prime`], but instead of counting primes up
+    /// to an estimate of the nth prime, and then iterating through the primes from there, it builds
+    /// a [`PrimeCountIndex`] and binary searches it for the byte that holds the nth prime. Building
+    /// the index counts the primes of every byte once, which is just as much as the bounds check
+    /// of [`PrimeData::try_nth_prime`] reads, but then it doesn't iterate through any primes. If
+    /// you need more than one prime from the same data, build the index once with
+    /// [`PrimeData::count_index`] and call [`PrimeData::nth_prime_indexed`] instead.
+    /// 
+    /// Returns the same errors as [`PrimeData::try_nth_prime`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let data = PrimeData::generate(7..=105_000);
+    /// 
+    /// assert_eq!(data.nth_prime_fast(1).unwrap(), 2);
+    /// assert_eq!(data.nth_prime_fast(19).unwrap(), 67);
+    /// assert_eq!(data.nth_prime_fast(10001).unwrap(), 104743);
+    /// assert!(data.nth_prime_fast(20000).is_err());
+    /// ```
+    pub fn nth_prime_fast(&self, nth: u64) -> PrimeResult<u64> {
+        self.nth_prime_indexed(&self.count_index(), nth)
+    }
+
+    /// Builds an index with running prime counts over the data
+    /// 
+    /// See [`PrimeCountIndex`] and [`PrimeData::nth_prime_indexed`].
+    pub fn count_index(&self) -> PrimeCountIndex {
+        PrimeCountIndex::new(self)
+    }
+
+    /// Retrieves the nth prime number, using an index built from the data
+    /// 
+    /// This gives the same results as [`PrimeData::try_nth_prime`], but instead of counting primes
+    /// every call, it binary searches the [index](PrimeCountIndex), so it's way faster if you need
+    /// lots of primes from the same data.
+    /// 
+    /// Returns an [OutOfBounds](crate::error::ErrorType::OutOfBounds) error if `nth` is zero.
+    /// 
+    /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error in three situations:
+    /// 
+    /// * The data starts anywhere after 7, just like [`PrimeData::try_nth_prime`].
+    /// * The data doesn't have n primes. The error then holds the range after the data's end that
+    ///   is guaranteed to contain the nth prime.
+    /// * The index was built from data with some other range. The error then holds the data's range.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let data = PrimeData::generate(0..=105_000);
+    /// let index = data.count_index();
+    /// 
+    /// assert_eq!(data.nth_prime_indexed(&index, 1).unwrap(), 2);
+    /// assert_eq!(data.nth_prime_indexed(&index, 4).unwrap(), 7);
+    /// assert_eq!(data.nth_prime_indexed(&index, 10001).unwrap(), 104743);
+    /// assert!(data.nth_prime_indexed(&index, 20000).is_err());
+    /// 
+    /// let other_index = PrimeData::generate(0..=1000).count_index();
+    /// assert!(data.nth_prime_indexed(&other_index, 5).is_err());
+    /// ```
+    pub fn nth_prime_indexed(&self, index: &PrimeCountIndex, nth: u64) -> PrimeResult<u64> {
+        match nth {
+            0 => {
+                let error = PrimeError {
+                    context: ErrorContext { action: ErrorAction::Reading, source: ErrorSource::PrimeData, data_range: Some(self.range.clone()), suggestion: None },
+                    error: ErrorType::OutOfBounds(nth)
+                };
+
+                return Err(error)
+            },
+            1 => return Ok(2),
+            2 => return Ok(3),
+            3 => return Ok(5),
+            _ => {}
+        }
+
+        let (start, end) = self.range();
+
+        let missing_range = if index.range != self.range || index.prefix.len() != self.data.len() + 1 {
+            Some(self.range.clone())
+        } else if start > 7 {
+            Some(7..=(start - 1))
+        } else if index.prefix[self.data.len()] < nth - 3 {
+            let (_, upper_bound) = super::estimate::nth_prime_bounds(nth).into_inner();
+            Some(end.saturating_add(1)..=cmp::max(upper_bound, end.saturating_add(1)))
+        } else {
+            None
+        };
+
+        if let Some(missing_range) = missing_range {
+            let error = PrimeError {
+                context: ErrorContext { action: ErrorAction::Reading, source: ErrorSource::PrimeData, data_range: Some(self.range.clone()), suggestion: None },
+                error: ErrorType::NotEnoughData(missing_range)
+            };
+
+            return Err(error)
+        }
+
+        // the bytes don't store 2, 3, and 5, so we look for the (nth - 3)th prime among them
+        let target = nth - 3;
+        #[cfg(feature = "tracing")]
+        let mut probes = 0u64;
+
+        let byte_index = index.prefix.partition_point(|&count| {
+            #[cfg(feature = "tracing")]
+            { probes += 1; }
+
+            count < target
+        }) - 1;
+        let (offset, byte) = self.masked_byte(byte_index);
+
+        let k_value = byte.as_k_values()[(target - index.prefix[byte_index] - 1) as usize];
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(steps = probes, "searched the prime count index");
+
+        Ok(30 * offset + k_value as u64)
+    }
+
+    /// Retrieves the nth prime number within the data's own range
+    /// 
+    /// Unlike [`PrimeData::nth_prime`], which counts from zero and therefore needs data starting at
+    /// 7 or earlier, this counts primes starting from the beginning of the data range, so `get_prime(1)`
+    /// is the smallest prime in the data. It works its way through whole bytes via their prime counts,
+    /// only decoding the byte that holds the prime.
+    /// 
+    /// Returns an [OutOfBounds](crate::error::ErrorType::OutOfBounds) error if `nth` is zero, or if
+    /// the data has less than `nth` primes.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let data = PrimeData::generate(100..=200);
+    /// 
+    /// assert_eq!(data.get_prime(1).unwrap(), 101);
+    /// assert_eq!(data.get_prime(5).unwrap(), 113);
+    /// assert_eq!(data.get_prime(21).unwrap(), 199);
+    /// assert!(data.get_prime(22).is_err());
+    /// assert!(data.get_prime(0).is_err());
+    /// ```
+    pub fn get_prime(&self, nth: u64) -> PrimeResult<u64> {
+        let (start, end) = self.range();
+
+        // primedata does not take 2, 3, and 5 into account
+        let small_primes: Vec<u64> = [2, 3, 5].into_iter().filter(|&p| start <= p && p <= end).collect();
+
+        if nth == 0 || self.is_empty() {
+            let error = PrimeError {
+                context: ErrorContext { action: ErrorAction::Reading, source: ErrorSource::PrimeData, data_range: Some(self.range.clone()), suggestion: None },
+                error: ErrorType::OutOfBounds(nth)
+            };
+
+            return Err(error)
+        }
+
+        if nth <= small_primes.len() as u64 {
+            return Ok(small_primes[nth as usize - 1])
+        }
+
+        let mut remaining = nth - small_primes.len() as u64;
+        for (offset, byte) in self.iter_bytes() {
+            let count = byte.count_primes();
+            if remaining <= count {
+                let k_value = byte.as_k_values()[remaining as usize - 1];
+                return Ok(30 * offset + k_value as u64)
+            }
+
+            remaining -= count;
+        }
+
+        let error = PrimeError {
+            context: ErrorContext { action: ErrorAction::Reading, source: ErrorSource::PrimeData, data_range: Some(self.range.clone()), suggestion: None },
+            error: ErrorType::OutOfBounds(nth)
+        };
+
+        Err(error)
+    }
+
+    /// Tries to verify if the given number is prime
+    /// 
+    /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error if both are true:
+    /// 
+    /// * The data range does not include x
+    /// * The data range does not contain the range between 7 and √x
+    /// 
+    /// See [`Self::check_prime`]
+    pub fn try_check_prime(&self, x: u64) -> PrimeResult<bool> {
+        if self.range.contains(&x) {
+            self.try_is_prime(x)
+        } else {
+
+            let sqrt = x.sqrt_floor();
+
+            if let Err(missing_range) = self.range.contains_range(&(7..=sqrt)) {
+
+                let error = PrimeError {
+                    context: ErrorContext { action: ErrorAction::Reading, source: ErrorSource::PrimeData, data_range: Some(self.range.clone()), suggestion: None },
+                    error: ErrorType::NotEnoughData(missing_range)
+                };
+    
+                return Err(error)
+            }
+
+            if (x == 2) || (x == 3) || (x == 5) { return Ok(true) }
+            if x.divisible_by(30) { return Ok(false) }
+
+            for prime in self.iter(7..=sqrt) {
+                if x.divisible_by(prime) { return Ok(false) }
+            }
+
+            Ok(true)
+        }
+    }
+
+    /// Verifies if the given number is prime
+    /// 
+    /// If we have information of all prime numbers between 0 and √x, we can verify if x is prime
+    /// by checking if none of those primes divide x. Therefore, when calling this function, if x
+    /// lies inside the data, it's O(1), but if the data has information about all primes up to √x,
+    /// it's O(n).
+    /// 
+    /// See [`Self::try_check_prime`] if you wish to return an error instead of panicking.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if both are true:
+    /// 
+    /// * The data range does not include x
+    /// * The data range does not contain the range between 7 and √x
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use prime_data::PrimeData;
+    /// 
+    /// let data = PrimeData::generate(7..=35);
+    /// 
+    /// assert!( data.check_prime(7));  // O(1)
+    /// assert!(!data.check_prime(35)); // O(1)
+    /// assert!( data.check_prime(1123));  // O(n)
+    /// assert!(!data.check_prime(1225));  // O(n)
+    /// ```
+    pub fn check_prime(&self, x: u64) -> bool {
+        self.try_check_prime(x).unwrap()
+    }
+
+    /// Tries to factorize the given number into prime factors.
+    /// 
+    /// If x is prime, this is found out by a primality test before dividing x by any primes, so
+    /// factorizing a prime is O(log x) instead of O(π(√x)). Trial division also stops once the
+    /// primes go past the cube root of what's left of x, since that's either a prime, the square of
+    /// a prime, or the product of two primes. The last case is split with Pollard's rho algorithm,
+    /// so a product of two primes of similar size takes around O(x^(1/4)) steps instead of O(π(√x)).
+    /// 
+    /// Returns a [NotEnoughData](crate::error::ErrorType::NotEnoughData) error if
+    /// the data (self) range does not contain the range `2..=sqrt(x)`.
+    /// 
+    /// Returns an [OutOfBounds](crate::error::ErrorType::OutOfBounds) error if x is zero, since it
+    /// has no prime factorization. One has an empty factorization.
+    /// 
+    /// See [`Self::factorize`]
+    #[cfg(feature = "factors")]
+    pub fn try_factorize(&self, x: u64) -> PrimeResult<super::Factorization> {
+        use super::utils::pollard_rho;
+
+        if x == 0 {
+            let error = PrimeError {
+                context: ErrorContext { action: ErrorAction::Reading, source: ErrorSource::PrimeData, data_range: Some(self.range.clone()), suggestion: None },
+                error: ErrorType::OutOfBounds(x)
+            };
+
+            return Err(error)
+        }
+
+        let mut number = x;
+        let sqrt = x.sqrt_floor();
+
+        if let Err(missing_range) = self.range.contains_range(&(2..=sqrt)) {
+            let error = PrimeError {
+                context: ErrorContext { action: ErrorAction::Reading, source: ErrorSource::PrimeData, data_range: Some(self.range.clone()), suggestion: None },
+                error: ErrorType::NotEnoughData(missing_range)
+            };
+
+            return Err(error)
+        }
+
+        let mut factorization = super::Factorization::new();
+
+        // a prime has no factors besides itself, so there's no need to divide it by anything
+        if miller_rabin(x) {
+            factorization.add_factor(x);
+            return Ok(factorization)
+        }
+
+        #[cfg(feature = "tracing")]
+        let mut divisors = 0u64;
+
+        for prime in self.iter(2..=sqrt) {
+            // the number has no factors below this prime, so if it's smaller than its square, it's prime
+            if prime * prime > number { break }
+
+            // past the cube root, the number has at most two prime factors, so it's either a prime,
+            // the square of one, or the product of two different ones
+            if (prime as u128).pow(3) > number as u128 {
+                if miller_rabin(number) { break }
+
+                let factor = if number.is_square() { number.sqrt_floor() } else { pollard_rho(number) };
+                factorization.add_factor(factor);
+                factorization.add_factor(number / factor);
+
+                number = 1;
+                break
+            }
+
+            #[cfg(feature = "tracing")]
+            { divisors += 1; }
+
+            if number.divisible_by(prime) {
+                while number.divisible_by(prime) {
+                    factorization.add_factor(prime);
+                    number /= prime;
+                }
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(divisors, "trial division");
+
+        if number > 1 {
+            factorization.add_factor(number);
+        }
+
+        Ok(factorization)
+    }
+
+    /// Factorizes the given number into prime factors.
+    /// 
+    /// See [`Self::try_factorize`] if you wish to return an error instead of panicking.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the data (self) range does not contain the range `2..=sqrt(x)`, or if x is zero.
+    /// 
+    /// # Examples
+    /// 
+    /// This function returns a [Factorization](super::Factorization) struct. Read
+    /// to the struct's documentation for its methods and examples.
+    #[cfg(feature = "factors")]
+    pub fn factorize(&self, x: u64) -> super::Factorization {
+        self.try_factorize(x).unwrap()
+    }
+}
+
+// private methods
+impl PrimeData {
+    // Sieves all primes in the given range, using the primes in self
+    // Should only be called after verifying that self contains the range 7..=sqrt(range.end)
+    pub(crate) fn sieve(&self, range: RangeInclusive<u64>) -> Self {
+        let end_sqrt = range.end().sqrt_floor();
+
+        let mut expanded_data = Self::create_empty(range.clone());
+        if expanded_data.is_empty() { return expanded_data }
+
+        expanded_data.cross_out_multiples(self.iter(7..=end_sqrt), range);
+
+        expanded_data
+    }
+
+    // Sets every multiple of the given primes within the range as nonprime, except the primes themselves
+    // Should only be called if self contains the range
+    fn cross_out_multiples(&mut self, primes: impl Iterator<Item = u64>, range: RangeInclusive<u64>) {
+        let (start, end) = range.bounds();
+
+        for prime in primes {
+            // any multiple p * m with m < p has a prime factor smaller than p, so it was already
+            // crossed out by a previous prime
+            let lower_bound = cmp::max(start.div_ceil(prime), prime);
+            let upper_bound = Divisible::div_floor(end, prime);
+
+            #[cfg(feature = "tracing")]
+            let mut multipliers = 0u64;
+
+            for multiplier in CoprimeIter::new(lower_bound..=upper_bound) {
+                let composite_number = prime * multiplier;
+                self.set_nonprime(composite_number).unwrap();
+
+                #[cfg(feature = "tracing")]
+                { multipliers += 1; }
+            }
+
+            #[cfg(feature = "tracing")]
+            tracing::trace!(prime, multipliers, "sieved multiples of prime");
+        }
+    }
+
+    // Creates "empty" data, with all bits set to one.
+    // Should only be called by expansion functions.
+    fn create_empty(range: RangeInclusive<u64>) -> Self {
+        let data_start = Divisible::div_floor(*range.start(), 30);
+        let data_length = Self::data_length(&range);
+
+        if data_length == 0 {
+            return Self { data: vec![], range }
+        }
+
+        let mut data = vec![PrimeByte::new(); data_length as usize];
+
+        // We want 1 to be set as nonprime by default
+        if data_start == 0 {
+            data[0].set_nonprime(1).unwrap();
         }
 
         Self { data, range }
     }
 
+    // How many bytes the data for the given range takes
+    fn data_length(range: &RangeInclusive<u64>) -> u64 {
+        let data_start = Divisible::div_floor(*range.start(), 30);
+        let data_end   = (*range.end()).div_ceil(30);
+
+        data_end.saturating_sub(data_start)
+    }
+
+    // Verifies that creating data for the given range won't allocate more than the limit
+    pub(crate) fn check_data_length(range: &RangeInclusive<u64>, action: ErrorAction) -> PrimeResult<()> {
+        let data_length = Self::data_length(range);
+        let limit = super::max_data_bytes();
+
+        if data_length > limit {
+            let error = PrimeError {
+                context: ErrorContext { action, source: ErrorSource::PrimeData, data_range: None, suggestion: None },
+                error: ErrorType::TooLarge { bytes: data_length, limit }
+            };
+
+            return Err(error)
+        }
+
+        Ok(())
+    }
+
     fn set_nonprime(&mut self, nonprime: u64) -> PrimeResult<bool> {
         if self.range.contains(&nonprime) {
             let data_index = (nonprime / 30) as usize - self.offset();
             let k_value = (nonprime % 30) as u8;
 
-            match self.data[data_index].set_nonprime(k_value) {
+            // if the range ends at a multiple of 30, there's no byte for it
+            let byte_result = match self.data.get_mut(data_index) {
+                Some(byte) => byte.set_nonprime(k_value),
+                None => Err(()),
+            };
+
+            match byte_result {
                 Ok(boolean) => Ok(boolean),
                 Err(()) => {
                     let error = PrimeError {
                         context: ErrorContext {
                             action: ErrorAction::Modifying,
-                            source: ErrorSource::PrimeData
+                            source: ErrorSource::PrimeData,
+                            data_range: Some(self.range.clone()),
+                            suggestion: None,
                         },
                         error: ErrorType::OutOfBounds(nonprime)
                     };
@@ -740,12 +2225,44 @@ impl PrimeData {
         } else {
 
             let error = PrimeError {
-                context: ErrorContext { action: ErrorAction::Modifying, source: ErrorSource::PrimeData },
+                context: ErrorContext { action: ErrorAction::Modifying, source: ErrorSource::PrimeData, data_range: Some(self.range.clone()), suggestion: None },
                 error: ErrorType::OutOfBounds(nonprime)
             };
 
-            Err(error)
-        }
+            Err(error)
+        }
+    }
+
+    // Retrieves the byte at the given index, along with its offset, clearing every bit outside of the range
+    fn masked_byte(&self, index: usize) -> (u64, PrimeByte) {
+        let (start, end) = self.range();
+        let byte_offset = (self.offset() + index) as u64;
+        let byte_start = 30 * byte_offset;
+
+        let lower = start.saturating_sub(byte_start) as u8;
+        let upper = cmp::min(end - byte_start, 30) as u8;
+
+        (byte_offset, self.data[index].masked(lower..=upper))
+    }
+
+    // Retrieves the indexes of the first and last bytes holding numbers within the range, along with
+    // the bounds (mod 30) to read from each of them. Multiples of 30 are never coprime with 30, so a
+    // range ending at one simply ends on the byte before it.
+    // Should only be called if self contains the range, and the range has some number coprime with 30
+    fn byte_span(&self, range: &RangeInclusive<u64>) -> (usize, usize, u8, u8) {
+        let (start, end) = range.bounds();
+        let last = if end.divisible_by(30) { end - 1 } else { end };
+
+        let first_index = (start / 30) as usize - self.offset();
+        let last_index = (last / 30) as usize - self.offset();
+
+        (first_index, last_index, (start % 30) as u8, (last % 30) as u8)
+    }
+
+    // How many integers the data range has, zero if it's empty
+    fn integer_count(&self) -> u128 {
+        let (start, end) = self.range();
+        if self.is_empty() { 0 } else { (end - start) as u128 + 1 }
     }
 
     // Retrieves an index such that `self.data[index]` contains x
@@ -776,6 +2293,13 @@ impl PrimeData {
     }
 }
 
+impl Default for PrimeData {
+    /// Same as [`PrimeData::new`]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 use std::fmt;
 impl fmt::Debug for PrimeData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -790,7 +2314,7 @@ impl fmt::Debug for PrimeData {
         let offset = self.offset();
         for (idx, chunk) in self.data.chunks(bytes_per_line).enumerate() {
             let outer_offset = offset + (idx * bytes_per_line);
-            let mut starter = format!("# ");
+            let mut starter = String::from("# ");
             for (i, byte) in chunk.iter().enumerate() {
                 let inner_offset = outer_offset + i;
                 starter.push_str(&format!("{} ", print_byte(byte, inner_offset, digit_len)));
@@ -833,4 +2357,763 @@ impl fmt::Debug for PrimeData {
     }
 
 #[cfg(test)]
-mod tests {}
\ No newline at end of file
+mod tests {
+    use super::{PrimeData, Divisible, IntSqrt};
+    use std::{cmp, ops::RangeInclusive};
+
+    #[test]
+    fn iter_up_to_data_end() {
+        // every residue mod 30 for the range end, so the last byte is hit at both of its bounds
+        for end in 60..=120 {
+            let data = PrimeData::generate(0..=end);
+            let naive = (2..=end).filter(|&x| (2..x).all(|d| !x.divisible_by(d))).collect::<Vec<u64>>();
+
+            assert_eq!(data.iter_all().collect::<Vec<u64>>(), naive);
+            assert_eq!(data.iter(31..=end).count(), naive.iter().filter(|&&p| p >= 31).count());
+        }
+    }
+
+    #[test]
+    fn iter_over_offset_data() {
+        for (start, end) in [(29, 59), (30, 60), (31, 89), (59, 90), (61, 119), (90, 90)] {
+            let data = PrimeData::generate(start..=end);
+            let naive = (start..=end).filter(|&x| x > 1 && (2..x).all(|d| !x.divisible_by(d))).collect::<Vec<u64>>();
+
+            assert_eq!(data.iter_all().collect::<Vec<u64>>(), naive);
+        }
+    }
+
+    #[test]
+    fn filter_short_circuits() {
+        let data = PrimeData::generate(100..=200);
+        // claims every multiple of 7 is prime, which only the filter would say
+        let filter = |x: u64| if x.divisible_by(7) { Some(true) } else { None };
+
+        for x in 100..=200 {
+            let expected = x.divisible_by(7) || data.is_prime(x);
+            assert_eq!(data.is_prime_with_filter(x, filter).unwrap(), expected, "{}", x);
+        }
+
+        assert!(data.is_prime_with_filter(7, filter).unwrap());
+        assert!(data.is_prime_with_filter(11, filter).is_err());
+    }
+
+    #[test]
+    fn iter_empty_ranges() {
+        let data = PrimeData::generate(0..=100);
+        assert_eq!(data.iter(RangeInclusive::new(95, 40)).next(), None);
+        assert_eq!(data.iter(100..=100).next(), None);
+
+        assert_eq!(PrimeData::generate(RangeInclusive::new(90, 40)).iter_all().next(), None);
+        assert_eq!(PrimeData::generate(RangeInclusive::new(31, 30)).iter_all().next(), None);
+    }
+
+    #[test]
+    fn iter_inverted_ranges_never_panic() {
+        for (start, end) in [(0, 100), (31, 30), (60, 59), (61, 60), (59, 31), (29, 29), (61, 119)] {
+            let data = PrimeData::generate(start..=end);
+
+            for first in start..=(end + 40) {
+                for last in start..first {
+                    match data.try_iter(first..=last) {
+                        Ok(mut iter) => assert_eq!(iter.next(), None),
+                        Err(_) => assert!(last > end),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn unaligned_start_is_strict() {
+        let is_prime = |x: u64| x > 1 && (2..x).all(|d| !x.divisible_by(d));
+
+        for start in [1, 7, 11, 14, 29, 31, 49, 50, 61, 89, 91] {
+            for end in (start + 1)..=(start + 70) {
+                let data = PrimeData::generate(start..=end);
+                let primes = (start..=end).filter(|&x| is_prime(x)).collect::<Vec<u64>>();
+
+                assert_eq!(data.iter_all().collect::<Vec<u64>>(), primes);
+                assert_eq!(data.count_primes(), primes.len() as u64);
+
+                for x in start..=end {
+                    assert_eq!(data.is_prime(x), is_prime(x));
+                    assert_eq!(data.count_primes_in_range(x..=end), primes.iter().filter(|&&p| p >= x).count() as u64);
+                }
+
+                // the first byte stores bits below the start, but those were never sieved
+                for below in 0..start {
+                    assert!(data.try_is_prime(below).is_err());
+                    assert!(data.try_count_primes_in_range(below..=end).is_err());
+                }
+
+                // iterating may only start below the data if none of the skipped numbers could be prime
+                for below in 0..start {
+                    let trivial = (below..start).all(|x| x < 2 || (x > 5 && [2, 3, 5].iter().any(|&p| x.divisible_by(p))));
+                    assert_eq!(data.try_iter(below..=end).is_ok(), trivial);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn iter_bytes_reconstructs_primes() {
+        for (start, end) in [(0, 1000), (1, 29), (7, 7), (14, 50), (31, 59), (60, 90), (61, 1019)] {
+            let data = PrimeData::generate(start..=end);
+
+            let primes = [2, 3, 5].into_iter()
+            .filter(|x| (start..=end).contains(x))
+            .chain(data.iter_bytes().flat_map(|(offset, byte)| byte.as_primes(offset)))
+            .collect::<Vec<u64>>();
+
+            assert_eq!(primes, data.iter_all().collect::<Vec<u64>>());
+        }
+    }
+
+    // generation as it used to be, recursing into the square root
+    fn recursive_generate(range: RangeInclusive<u64>) -> PrimeData {
+        let (start, end) = range.into_inner();
+
+        if end <= 900 {
+            PrimeData::new().expand(start..=end)
+        } else {
+            recursive_generate(0..=end.sqrt_floor()).expand(start..=end)
+        }
+    }
+
+    #[test]
+    fn generate_matches_recursive() {
+        let ranges = [
+            (0, 0), (0, 30), (0, 900), (0, 901), (0, 810_001), (0, 2_000_000), (29, 31), (100, 200),
+            (899, 1000), (12_345, 67_890), (999_000, 1_001_000), (10_000_000_000, 10_000_100_000),
+        ];
+
+        for (start, end) in ranges {
+            let data = PrimeData::generate(start..=end);
+            let expected = recursive_generate(start..=end);
+
+            assert_eq!(data.range, expected.range);
+            assert!(data.data == expected.data);
+        }
+    }
+
+    #[test]
+    fn expand_from_given_base() {
+        use crate::error::ErrorType;
+
+        // the base needs all primes in 7..=100 to reach 10_000
+        for (start, end) in [(0, 99), (8, 100), (7, 50), (31, 30)] {
+            let base = PrimeData::generate(start..=end);
+            let error = PrimeData::expand_from(&base, 0..=10_000).unwrap_err();
+            assert!(matches!(error.error, ErrorType::NotEnoughData(_)));
+        }
+
+        let base = PrimeData::generate(7..=100);
+        for (start, end) in [(0, 10_000), (0, 0), (29, 31), (5_000, 6_000), (9_990, 10_200)] {
+            let data = PrimeData::expand_from(&base, start..=end).unwrap();
+            let expected = PrimeData::generate(start..=end);
+
+            assert_eq!(data.range, expected.range);
+            // ranges within the base are copied from it, so bits outside of the range may differ
+            if base.range.contains(&start) && base.range.contains(&end) {
+                assert!(data.iter_all().eq(expected.iter_all()));
+            } else {
+                assert!(data.data == expected.data);
+            }
+        }
+
+        // a wrong base is trusted, so multiples of 7 are never crossed out
+        let mut wrong_base = PrimeData::generate(0..=100);
+        wrong_base.set_nonprime_unchecked(7).unwrap();
+        let data = PrimeData::expand_from(&wrong_base, 0..=1000).unwrap();
+        assert!(data.is_prime(49) && data.is_prime(77));
+        assert!(!data.is_prime(121));
+    }
+
+    #[test]
+    fn count_single_points() {
+        let datasets = [PrimeData::new(), PrimeData::generate(0..=1000), PrimeData::generate(0..=90)];
+
+        for data in datasets.iter() {
+            assert_eq!(data.count_primes_in_range(0..=0), 0);
+            assert_eq!(data.count_primes_in_range(1..=1), 0);
+            assert_eq!(data.count_primes_in_range(2..=2), 1);
+            assert_eq!(data.count_primes_in_range(30..=30), 0);
+        }
+
+        // offset data, where the points lie on the data's edges
+        for (start, end) in [(30, 90), (29, 31), (61, 90), (90, 90)] {
+            let data = PrimeData::generate(start..=end);
+
+            for x in start..=end {
+                let expected = if x > 1 && (2..x).all(|d| !x.divisible_by(d)) { 1 } else { 0 };
+                assert_eq!(data.count_primes_in_range(x..=x), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn buckets_match_individual_counts() {
+        let data = PrimeData::generate(0..=10_000);
+        let boundaries = [
+            vec![0, 10_000], vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10], vec![0, 29, 30, 31, 59, 60, 61, 10_000],
+            vec![0, 100, 100, 100, 1000, 10_000], (0..=10_000).step_by(37).collect(), vec![6, 6], vec![7], vec![],
+        ];
+
+        for boundaries in boundaries {
+            let counts = data.count_primes_buckets(&boundaries).unwrap();
+            assert_eq!(counts.len(), boundaries.len().saturating_sub(1));
+
+            for (count, pair) in counts.iter().zip(boundaries.windows(2)) {
+                assert_eq!(*count, data.count_primes_in_range((pair[0] + 1)..=pair[1]));
+            }
+
+            if let [first, .., last] = boundaries[..] {
+                assert_eq!(counts.iter().sum::<u64>(), data.count_primes_in_range((first + 1)..=last));
+            }
+        }
+
+        // offset data, with buckets starting right at the data start
+        let data = PrimeData::generate(1_013..=2_027);
+        let counts = data.count_primes_buckets(&[1_013, 1_500, 2_027]).unwrap();
+        assert_eq!(counts, vec![data.count_primes_in_range(1_014..=1_500), data.count_primes_in_range(1_501..=2_027)]);
+        assert_eq!(counts.iter().sum::<u64>() + 1, data.count_primes());
+    }
+
+    #[test]
+    fn generate_many_matches_generate() {
+        let windows = [10_000_000..=10_010_000, 123_456..=130_000, 99_999_000..=100_000_000];
+        let data = PrimeData::generate_many(10_000, &windows).unwrap();
+
+        assert_eq!(data.len(), 3);
+        for (window, data) in windows.iter().zip(data) {
+            let expected = PrimeData::generate(window.clone());
+
+            assert_eq!(data.range, expected.range);
+            assert_eq!(data.iter_all().collect::<Vec<u64>>(), expected.iter_all().collect::<Vec<u64>>());
+        }
+
+        assert!(PrimeData::generate_many(9_999, &windows).is_err());
+        assert!(PrimeData::generate_many(0, &[0..=48]).unwrap()[0].iter_all().eq([2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47]));
+    }
+
+    #[test]
+    fn generate_below_30_matches_expand() {
+        for start in 0..=40 {
+            for end in 0..=29 {
+                let range = RangeInclusive::new(start, end);
+                let data = PrimeData::generate(range.clone());
+                let expected = PrimeData::new().expand(range);
+
+                assert_eq!(data.range, expected.range);
+                assert!(data.data == expected.data);
+            }
+        }
+    }
+
+    #[test]
+    fn nth_prime_indexed_matches_nth_prime() {
+        for (start, end) in [(0, 10_000), (3, 10_000), (6, 10_000), (7, 10_000), (1, 29), (0, 7), (5, 60), (0, 104_743)] {
+            let data = PrimeData::generate(start..=end);
+            let index = data.count_index();
+            let prime_count = PrimeData::generate(0..=end).count_primes();
+
+            for nth in 1..=prime_count {
+                assert_eq!(data.nth_prime_indexed(&index, nth).unwrap(), data.nth_prime(nth));
+            }
+
+            assert!(data.nth_prime_indexed(&index, prime_count + 1).is_err());
+            assert!(data.nth_prime_indexed(&index, 0).is_err());
+        }
+
+        let data = PrimeData::generate(8..=1000);
+        assert!(data.nth_prime_indexed(&data.count_index(), 10).is_err());
+    }
+
+    #[test]
+    fn nth_prime_fast_matches_nth_prime() {
+        for start in 0..=7 {
+            let data = PrimeData::generate(start..=60_000);
+            let count = 3 + data.count_primes_in_range(7..=60_000);
+
+            for nth in (1..=count).step_by(7).chain([count]) {
+                assert_eq!(data.nth_prime_fast(nth).unwrap(), data.nth_prime(nth));
+            }
+            assert!(data.nth_prime_fast(count + 1).is_err());
+            assert!(data.nth_prime_fast(0).is_err());
+        }
+
+        assert!(PrimeData::generate(8..=1000).nth_prime_fast(5).is_err());
+    }
+
+    #[test]
+    fn count_without_coprimes_skips_data() {
+        // no data at all, so reading any byte would panic
+        let data = PrimeData { data: vec![], range: 0..=30 };
+
+        assert_eq!(data.count_primes_in_range(24..=26), 0);
+        assert_eq!(data.count_primes_in_range(8..=10), 0);
+        assert_eq!(data.count_primes_in_range(2..=6), 3);
+        assert_eq!(data.count_primes_in_range(30..=30), 0);
+    }
+
+    #[test]
+    fn set_nonprime_at_data_end() {
+        let mut data = PrimeData::generate(0..=90);
+        assert!(data.set_nonprime_unchecked(90).is_err());
+
+        let mut data = PrimeData::generate(90..=90);
+        assert!(data.set_nonprime_unchecked(90).is_err());
+    }
+
+    #[test]
+    fn split_and_rejoin_counts() {
+        for (start, end) in [(0, 1000), (29, 331), (30, 360), (61, 479)] {
+            let data = PrimeData::generate(start..=end);
+
+            for n in start..=end {
+                let (left, right) = data.split_at(n).unwrap();
+                let shared = if data.is_prime(n) { 1 } else { 0 };
+
+                assert_eq!(left.count_primes() + right.count_primes() - shared, data.count_primes());
+                assert_eq!(left.iter_all().chain(right.iter_all().skip(shared as usize)).collect::<Vec<u64>>(),
+                    data.iter_all().collect::<Vec<u64>>());
+            }
+        }
+    }
+
+    #[test]
+    fn absurd_ranges_are_too_large() {
+        use crate::error::ErrorType;
+
+        let error = PrimeData::try_generate(0..=u64::MAX).unwrap_err();
+        assert!(matches!(error.error, ErrorType::TooLarge { bytes, .. } if bytes == u64::MAX.div_ceil(30)));
+
+        let error = PrimeData::new().try_expand(0..=u64::MAX).unwrap_err();
+        assert!(matches!(error.error, ErrorType::NotEnoughData(_)));
+
+        let base = PrimeData::generate(0..=1_000_000);
+        let error = base.try_expand((u64::MAX / 2)..=u64::MAX).unwrap_err();
+        assert!(matches!(error.error, ErrorType::NotEnoughData(_)));
+
+        let error = base.try_expand(0..=1_000_000_000_000).unwrap_err();
+        assert!(matches!(error.error, ErrorType::TooLarge { .. }));
+    }
+
+    #[test]
+    fn sort_by_range() {
+        let mut pieces = [(500, 600), (0, 1000), (0, 30), (31, 30), (500, 510), (61, 90)]
+        .map(|(start, end)| PrimeData::generate(RangeInclusive::new(start, end)));
+
+        pieces.sort_by(|a, b| a.range_cmp(b));
+        let ranges = pieces.iter().map(|data| data.range()).collect::<Vec<(u64, u64)>>();
+        assert_eq!(ranges, vec![(0, 30), (0, 1000), (31, 30), (61, 90), (500, 510), (500, 600)]);
+
+        // (0, 1000) covers more than all others, and (31, 30) covers nothing
+        assert!(pieces.iter().all(|data| data.range() == (0, 1000) || pieces[1].covers_more_than(data)));
+        assert!(pieces.iter().all(|data| !pieces[2].covers_more_than(data)));
+        assert!(!pieces[2].covers_more_than(&PrimeData::generate(RangeInclusive::new(91, 90))));
+        assert_eq!(pieces[1].range_cmp(&pieces[1]), cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn errors_show_data_range() {
+        let data = PrimeData::generate(73..=144);
+
+        let message = data.try_count_primes_in_range(72..=100).unwrap_err().to_string();
+        assert!(message.contains("72..=72"));
+        assert!(message.contains("data covers 73..=144"));
+
+        let message = data.try_is_prime(145).unwrap_err().to_string();
+        assert!(message.contains("145"));
+        assert!(message.contains("data covers 73..=144"));
+
+        // generating has no data to begin with
+        let message = PrimeData::try_generate(0..=u64::MAX).unwrap_err().to_string();
+        assert!(!message.contains("data covers"));
+    }
+
+    #[test]
+    fn iter_skip_matches_skip() {
+        for (start, end) in [(0, 1000), (2, 1000), (4, 1000), (6, 1000), (31, 59), (100, 961), (29, 29), (31, 30)] {
+            let data = PrimeData::generate(start..=end);
+            let primes = data.iter_all().collect::<Vec<u64>>();
+
+            for (from, to) in [(start, end), (start + 1, end), (start, end.saturating_sub(31)), (start + 29, end)] {
+                for skip in 0..=(primes.len() as u64 + 2) {
+                    let expected = data.iter(from..=to).skip(skip as usize).collect::<Vec<u64>>();
+                    assert_eq!(data.iter_skip(skip, from..=to).unwrap().collect::<Vec<u64>>(), expected);
+                }
+            }
+        }
+
+        assert!(PrimeData::generate(0..=100).iter_skip(10, 0..=101).is_err());
+    }
+
+    #[test]
+    fn iter_step_every_other_prime() {
+        let data = PrimeData::generate(0..=100);
+        let primes = data.iter_all().collect::<Vec<u64>>();
+
+        let every_other = data.iter_step(0..=100, 2).unwrap().collect::<Vec<u64>>();
+        assert_eq!(every_other, vec![2, 5, 11, 17, 23, 31, 41, 47, 59, 67, 73, 83, 97]);
+
+        for step in 1..=30 {
+            let expected = primes.iter().copied().step_by(step).collect::<Vec<u64>>();
+            assert_eq!(data.iter_step(0..=100, step).unwrap().collect::<Vec<u64>>(), expected);
+        }
+
+        assert!(data.iter_step(0..=100, 0).is_err());
+        assert!(data.iter_step(0..=101, 1).is_err());
+    }
+
+    #[test]
+    fn small_nth_prime_matches_iteration() {
+        let data = PrimeData::generate(0..=104_729);
+        let primes: Vec<u64> = data.iter_all().collect();
+
+        for nth in 1..=10_000 {
+            assert_eq!(data.nth_prime(nth), primes[nth as usize - 1], "{}", nth);
+        }
+        assert_eq!(data.nth_prime(19), 67);
+
+        // the data is enough for the small primes, but not its whole window
+        assert_eq!(PrimeData::generate(0..=100).nth_prime(19), 67);
+        assert_eq!(PrimeData::generate(7..=67).nth_prime(19), 67);
+    }
+
+    #[test]
+    fn nth_prime_beyond_data_is_an_error() {
+        use crate::error::ErrorType;
+
+        let data = PrimeData::generate(0..=100);
+        assert_eq!(data.try_nth_prime(25).unwrap(), 97);
+
+        for nth in [26, 100, 1000, 100_000] {
+            let error = data.try_nth_prime(nth).unwrap_err();
+            assert!(matches!(error.error, ErrorType::NotEnoughData(range) if *range.start() == 101));
+        }
+
+        let error = PrimeData::generate(8..=1000).try_nth_prime(10).unwrap_err();
+        assert!(matches!(error.error, ErrorType::NotEnoughData(range) if range == (7..=7)));
+        assert!(PrimeData::generate(0..=6).try_nth_prime(4).is_err());
+        assert_eq!(PrimeData::generate(7..=7).try_nth_prime(4).unwrap(), 7);
+    }
+
+    #[test]
+    fn merge_segments_matches_generate() {
+        let data = PrimeData::generate(0..=10_000);
+
+        let segments = (0..10u64)
+        .map(|segment| PrimeData::generate((segment * 1000 + 1)..=((segment + 1) * 1000)))
+        .chain([PrimeData::generate(0..=0)])
+        .rev()
+        .collect::<Vec<PrimeData>>();
+
+        let merged = PrimeData::merge_all(segments).unwrap();
+        assert_eq!(merged.range, data.range);
+        assert!(merged.data == data.data);
+
+        // overlapping pieces, as well as pieces inside others, at every alignment
+        for step in [1, 7, 29, 30, 31, 61, 450] {
+            let mut pieces = Vec::new();
+            let mut start = 0;
+            while start <= 1000 {
+                let end = cmp::min(start + step, 1000);
+                pieces.push(PrimeData::generate(start..=end));
+                pieces.push(PrimeData::generate(start..=start));
+                start = end + (step % 2);
+                if end == 1000 { break }
+            }
+
+            let merged = PrimeData::merge_all(pieces).unwrap();
+            let expected = PrimeData::generate(0..=1000);
+            assert_eq!(merged.range, expected.range);
+            assert_eq!(merged.iter_all().collect::<Vec<u64>>(), expected.iter_all().collect::<Vec<u64>>());
+        }
+
+        assert!(PrimeData::merge_all(vec![]).unwrap().is_empty());
+
+        use crate::error::ErrorType;
+        let pieces = vec![PrimeData::generate(0..=100), PrimeData::generate(200..=300), PrimeData::generate(90..=150)];
+        let error = PrimeData::merge_all(pieces).unwrap_err();
+        assert!(matches!(error.error, ErrorType::NotEnoughData(range) if range == (151..=199)));
+    }
+
+    #[test]
+    fn with_capacity_reads_like_new() {
+        for (start, end) in [(0, 1000), (0, 0), (500, 90_000), (31, 30)] {
+            let data = PrimeData::with_capacity(start..=end);
+            let new = PrimeData::new();
+
+            assert!(data.data.capacity() >= cmp::max(PrimeData::data_length(&(start..=end)), 1) as usize);
+            assert_eq!(data.range, new.range);
+            assert!(data.data == new.data);
+        }
+
+        let error = PrimeData::try_with_capacity(0..=u64::MAX).unwrap_err();
+        assert!(matches!(error.error, crate::error::ErrorType::TooLarge { .. }));
+    }
+
+    #[test]
+    fn expand_in_place_matches_expand() {
+        let ranges = [(0, 0), (0, 1), (0, 29), (0, 30), (0, 31), (1, 100), (5, 60), (7, 900), (0, 1000), (3, 2)];
+
+        for (start, end) in ranges {
+            for new_end in [0, 30, 48, 49, 60, 91, 899, 900, 901, 10_000, 810_000, 1_000_000] {
+                let mut data = PrimeData::generate(RangeInclusive::new(start, end));
+                let expanded = data.expand_in_place(new_end);
+
+                if new_end <= end {
+                    assert!(expanded.is_ok());
+                    assert_eq!(data.range(), (start, end));
+                } else if let Ok(expected) = PrimeData::generate(RangeInclusive::new(start, end)).try_expand(start..=new_end) {
+                    expanded.unwrap();
+                    assert_eq!(data.range, expected.range);
+                    assert!(data.data == expected.data);
+                } else {
+                    assert!(expanded.is_err());
+                }
+            }
+        }
+
+        // growing step by step from the starter data
+        let mut data = PrimeData::with_capacity(0..=1_000_000);
+        let pointer = data.data.as_ptr();
+        for new_end in [100, 5_000, 20_000, 1_000_000] {
+            data.expand_in_place(new_end).unwrap();
+        }
+        assert_eq!(data.data.as_ptr(), pointer);
+        assert!(data.data == PrimeData::generate(0..=1_000_000).data);
+    }
+
+    #[test]
+    fn gap_histogram_counts_twin_primes() {
+        let data = PrimeData::generate(0..=100);
+
+        assert_eq!(data.gap_histogram(0..=100).unwrap(), vec![(1, 1), (2, 8), (4, 7), (6, 7), (8, 1)]);
+        assert_eq!(data.gap_histogram(3..=5).unwrap(), vec![(2, 1)]);
+        assert!(data.gap_histogram(89..=96).unwrap().is_empty());
+        assert!(data.gap_histogram(0..=101).is_err());
+
+        // past 2, every gap is even, and there are 1224 twin primes below 10^5
+        let data = PrimeData::generate(0..=100_000);
+        let histogram = data.gap_histogram(3..=100_000).unwrap();
+        assert!(histogram.iter().all(|&(gap, _)| gap % 2 == 0));
+        assert_eq!(histogram.iter().map(|&(_, frequency)| frequency).sum::<u64>(), data.count_primes() - 2);
+        assert_eq!(histogram[0], (2, 1224));
+    }
+
+    #[test]
+    fn default_is_new() {
+        let default = PrimeData::default();
+
+        assert_eq!(default.count_primes(), 10);
+        assert_eq!(default.range(), PrimeData::new().range());
+    }
+
+    #[test]
+    fn get_prime_counts_within_data() {
+        let data = PrimeData::generate(100..=200);
+        assert_eq!(data.get_prime(1).unwrap(), 101);
+
+        for (start, end) in [(0, 1000), (3, 97), (5, 5), (31, 31), (100, 200), (1234, 5678), (29, 61)] {
+            let data = PrimeData::generate(start..=end);
+            let primes: Vec<u64> = data.iter_all().collect();
+
+            for (nth, &prime) in (1..).zip(primes.iter()) {
+                assert_eq!(data.get_prime(nth).unwrap(), prime);
+            }
+            assert!(data.get_prime(primes.len() as u64 + 1).is_err());
+        }
+    }
+
+    #[test]
+    fn raw_bytes_round_trip() {
+        for (start, end) in [(0, 30), (0, 1000), (29, 31), (60, 60), (61, 59), (1234, 56789)] {
+            let data = PrimeData::generate(start..=end);
+            let wrapped = PrimeData::from_range_and_bytes(start..=end, data.as_raw_bytes()).unwrap();
+
+            assert!(wrapped.data == data.data);
+            assert_eq!(wrapped.range(), data.range());
+            assert!(wrapped.iter_all().eq(data.iter_all()));
+        }
+
+        let bytes = PrimeData::generate(0..=1000).as_raw_bytes();
+        assert!(PrimeData::from_range_and_bytes(0..=1000, bytes[1..].to_vec()).is_err());
+        assert!(PrimeData::from_range_and_bytes(0..=900, bytes).is_err());
+    }
+
+    #[test]
+    fn count_primes_matches_count_in_range() {
+        let data = PrimeData::generate(0..=3000);
+
+        for start in (0u64..200).chain(1000..1040) {
+            for end in (start.saturating_sub(2)..start + 100).chain([2999, 3000]) {
+                let piece = data.expand(start..=end);
+                assert_eq!(piece.count_primes(), data.count_primes_in_range(start..=end), "wrong count for {}..={}", start, end);
+            }
+        }
+    }
+
+    #[test]
+    fn iter_starts_below_data_when_trivial() {
+        let data = PrimeData::generate(100..=200);
+        let primes: Vec<u64> = data.iter_all().collect();
+
+        for start in [98, 99, 100] {
+            assert_eq!(data.iter(start..=200).collect::<Vec<u64>>(), primes);
+        }
+        assert_eq!(data.iter(98..=99).next(), None);
+
+        // 97 is prime, so skipping it would give wrong results
+        assert!(data.try_iter(97..=200).is_err());
+        assert!(data.try_iter(90..=200).is_err());
+
+        let data = PrimeData::generate(6..=30);
+        assert!(data.try_iter(0..=30).is_err());
+        assert!(data.try_iter(5..=30).is_err());
+
+        // 1 is neither prime nor composite, so it can be skipped just like 0
+        let data = PrimeData::generate(2..=30);
+        assert_eq!(data.iter(0..=30).count(), 10);
+    }
+
+    #[test]
+    fn count_by_reference_matches_owned() {
+        let data = PrimeData::generate(100..=10_000);
+
+        for start in (0..=10_100).step_by(97) {
+            for end in (start..=10_100).step_by(401) {
+                let range = start..=end;
+
+                match (data.try_count_primes_in_range(range.clone()), data.try_count_primes_in_range_ref(&range)) {
+                    (Ok(owned), Ok(by_ref)) => assert_eq!(owned, by_ref, "{:?}", range),
+                    (Err(_), Err(_)) => {},
+                    _ => panic!("only one of them failed for {:?}", range),
+                }
+            }
+        }
+
+        assert_eq!(data.count_primes_in_range_ref(&RangeInclusive::new(500, 400)), 0);
+    }
+
+    #[test]
+    fn count_primes_up_to_multiples_of_30() {
+        let is_prime = |x: u64| x > 1 && (2..=x.sqrt_floor()).all(|d| !x.divisible_by(d));
+
+        let mut naive = 0;
+        for n in (0u64..=6000).step_by(30) {
+            naive += (n.saturating_sub(29)..=n).filter(|&x| is_prime(x)).count() as u64;
+            let data = PrimeData::generate(0..=n);
+
+            assert_eq!(data.count_primes_in_range(0..=n), naive, "wrong count up to {}", n);
+            assert_eq!(data.count_primes(), naive);
+
+            // also within data that doesn't end at the same multiple
+            let bigger = PrimeData::generate(0..=n + 45);
+            assert_eq!(bigger.count_primes_in_range(0..=n), naive);
+
+            for start in [1, 7, 29, 30, 31] {
+                let expected = (start..=n).filter(|&x| is_prime(x)).count() as u64;
+                assert_eq!(bigger.count_primes_in_range(start..=n), expected);
+                if start <= n {
+                    assert_eq!(PrimeData::generate(start..=n).count_primes_in_range(start..=n), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn is_prime_out_of_range_suggests_check_prime() {
+        let data = PrimeData::generate(0..=50);
+
+        let message = data.try_is_prime(91).unwrap_err().to_string();
+        assert!(message.contains("Cannot access the given number: 91"));
+        assert!(message.contains("check_prime"));
+        assert!(!data.check_prime(91));
+
+        // the primes up to √2601 = 51 are not all there, so check_prime wouldn't help
+        let message = data.try_is_prime(2601).unwrap_err().to_string();
+        assert!(!message.contains("check_prime"));
+
+        let message = PrimeData::generate(100..=200).try_is_prime(91).unwrap_err().to_string();
+        assert!(!message.contains("check_prime"));
+    }
+
+    #[test]
+    fn agrees_with_over_overlap() {
+        let data = PrimeData::generate(0..=100);
+
+        for (start, end) in [(50, 150), (0, 30), (60, 60), (29, 31), (90, 120), (101, 200), (0, 1000)] {
+            let other = PrimeData::generate(start..=end);
+            assert!(data.agrees_with(&other));
+            assert!(other.agrees_with(&data));
+        }
+
+        // tampering with every single prime is noticed, as long as it's within both ranges
+        for prime in data.iter(7..=100).collect::<Vec<u64>>() {
+            let mut other = PrimeData::generate(50..=150);
+            if other.set_nonprime_unchecked(prime).is_ok() {
+                assert!(!data.agrees_with(&other));
+                assert!(!other.agrees_with(&data));
+            }
+
+            let mut other = PrimeData::generate(0..=100);
+            other.set_nonprime_unchecked(prime).unwrap();
+            assert!(!data.agrees_with(&other));
+            assert!(data.agrees_with(&PrimeData::generate((prime + 1)..=(prime + 100))));
+        }
+    }
+
+    #[test]
+    fn expand_below_source_start() {
+        let is_prime = |x: u64| x > 1 && (2..=x.sqrt_floor()).all(|d| !x.divisible_by(d));
+        let source = PrimeData::generate(7..=100);
+
+        for (start, end) in [(0, 10_000), (1, 10_000), (2, 50), (4, 1000), (0, 6), (0, 7)] {
+            let expanded = source.expand(start..=end);
+            let naive = (start..=end).filter(|&x| is_prime(x)).collect::<Vec<u64>>();
+
+            assert_eq!(expanded.iter_all().collect::<Vec<u64>>(), naive);
+            assert_eq!(expanded.count_primes(), naive.len() as u64);
+            for x in start..=cmp::min(end, 100) {
+                assert_eq!(expanded.is_prime(x), is_prime(x));
+            }
+        }
+
+        let expanded = source.expand(0..=10_000);
+        assert_eq!(expanded.iter_all().take(4).collect::<Vec<u64>>(), vec![2, 3, 5, 7]);
+    }
+
+    #[cfg(feature = "factors")]
+    #[test]
+    fn factorize_matches_trial_division() {
+        let data = PrimeData::generate(0..=1_500_000);
+
+        let naive = |mut x: u64| {
+            let mut factors = Vec::new();
+            let mut divisor = 2;
+            while divisor * divisor <= x {
+                while x.divisible_by(divisor) {
+                    factors.push(divisor);
+                    x /= divisor;
+                }
+                divisor += 1;
+            }
+            if x > 1 { factors.push(x) }
+
+            factors
+        };
+
+        let special = [
+            1_000_003 * 1_000_003, 999_983 * 1_000_003, 1009 * 1_000_000_007, 1009 * 1009 * 1_000_003,
+            2 * 999_983 * 999_983, 1 << 40, 3 * 3 * 3 * 7 * 7 * 1_000_003, 1021 * 1031 * 1033,
+            999_979 * 999_983, 1_299_709 * 1_299_721, 101 * 10_007 * 1_000_003, 2 * 3 * 5 * 7 * 10_007 * 10_009,
+        ];
+
+        for x in (1..20_000).chain(special) {
+            assert_eq!(data.factorize(x).prime_factors_with_multiplicity().collect::<Vec<u64>>(), naive(x), "wrong factors for {}", x);
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! Module dedicated to the PrimeStats struct
+
+use std::fmt;
+
+/// A quick summary of some [PrimeData](crate::PrimeData)
+/// 
+/// Created by the [`PrimeData::stats`](crate::PrimeData::stats) method. It implements [`Display`](fmt::Display),
+/// so you can simply print it.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use prime_data::PrimeData;
+/// let stats = PrimeData::generate(0..=1000).stats();
+/// 
+/// assert_eq!(
+///     stats.to_string(),
+///     "PrimeData (0 -> 1000): 168 primes in 34 bytes, density 0.1678, 34 bytes of memory"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PrimeStats {
+    /// The data's range, as (start, end)
+    pub range: (u64, u64),
+    /// How many [prime bytes](crate::PrimeByte) the data stores
+    pub byte_count: usize,
+    /// How many primes there are in the data's range
+    pub prime_count: u64,
+    /// The ratio between the amount of primes and the amount of integers in the data's range
+    pub density: f64,
+    /// How many bytes of memory the raw data takes
+    pub memory: usize,
+}
+
+impl fmt::Display for PrimeStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PrimeData ({} -> {}): {} primes in {} bytes, density {:.4}, {} bytes of memory",
+            self.range.0, self.range.1, self.prime_count, self.byte_count, self.density, self.memory)
+    }
+}
@@ -2,16 +2,26 @@ pub trait Number: Copy + Sized + Ord {}
 impl Number for u64 {}
 
 pub trait Logarithm: Number {
-    fn log2(self) -> Self;
     fn log10(self) -> Self;
 }
+// the logarithm is floored, and the logarithm of zero is treated as zero
 impl Logarithm for u64 {
-    fn log2(self) -> u64 {
-        (self as f64).log2() as u64
-    }
-
     fn log10(self) -> u64 {
-        (self as f64).log10() as u64
+        if self == 0 { return 0 }
+
+        // because of floating point error, this is only a first guess,
+        // that could be off by one near powers of 10
+        let mut log = (self as f64).log10() as u32;
+        let power = |exp: u32| 10u64.checked_pow(exp);
+
+        while power(log).map_or(true, |pow| pow > self) {
+            log -= 1;
+        }
+        while power(log + 1).is_some_and(|pow| pow <= self) {
+            log += 1;
+        }
+
+        log as u64
     }
 }
 
@@ -39,14 +49,7 @@ impl Divisible for u64 {
     }
 }
 
-pub trait Increment: Number {
-    fn increment(self) -> Self;
-}
-impl Increment for u64 {
-    fn increment(self) -> Self { self + 1 }
-}
-
-pub trait IntSqrt: Increment {
+pub trait IntSqrt: Number {
     fn isqrt(self) -> Result<Self, Self>;
 
     fn sqrt_floor(self) -> Self {
@@ -56,30 +59,28 @@ pub trait IntSqrt: Increment {
         }
     }
 
-    fn sqrt_ceil(self) -> Self {
-        match self.isqrt() {
-            Ok(value) => value,
-            Err(value) => value.increment(),
-        }
-    }
-
+    // only factorizing needs to know about squares
+    #[cfg_attr(not(feature = "factors"), allow(dead_code))]
     fn is_square(self) -> bool {
         self.isqrt().is_ok()
     }
 }
 impl IntSqrt for u64 {
     fn isqrt(self) -> Result<Self, Self> {
-        let sqrt = if self < (1 << 52) {
-            (self as f64).sqrt() as u64
-        } else {
-            // when a number is >= 2^52, because of floating point
-            // error, we need to "fix" it, treating it as a first
-            // guess for the actual sqrt
-            let first_guess = (self as f64).sqrt() as u64;
+        // because of floating point error, this is only a first guess
+        // for numbers >= 2^52, which could be off by a little
+        let mut sqrt = (self as f64).sqrt() as u64;
 
-            // apply one step of the babylonian method
-            (first_guess + (self / first_guess)) >> 1
-        };
+        // so we "fix" it, making sure sqrt² <= self < (sqrt + 1)², keeping
+        // in mind that squaring anything >= 2^32 overflows
+        let square = |x: u64| x.checked_mul(x);
+
+        while square(sqrt).map_or(true, |sq| sq > self) {
+            sqrt -= 1;
+        }
+        while square(sqrt + 1).is_some_and(|sq| sq <= self) {
+            sqrt += 1;
+        }
 
         if self == sqrt * sqrt {
             Ok(sqrt)
@@ -87,4 +88,311 @@ impl IntSqrt for u64 {
             Err(sqrt)
         }
     }
-}
\ No newline at end of file
+}
+
+/// Evaluates `(a * b) % m` without overflowing
+/// 
+/// The multiplication is done with [`u128`], so it's safe for any `u64` inputs.
+/// 
+/// # Panics
+/// 
+/// Panics if `m` is zero.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use prime_data::mulmod;
+/// 
+/// assert_eq!(mulmod(7, 8, 5), 1);
+/// // (2^64 - 1)^2 would overflow a u64
+/// assert_eq!(mulmod(u64::MAX, u64::MAX, 1_000_000_007), 114_944_269);
+/// assert_eq!(mulmod(u64::MAX, u64::MAX, u64::MAX), 0);
+/// assert_eq!(mulmod(u64::MAX - 1, u64::MAX - 1, u64::MAX), 1);
+/// ```
+pub fn mulmod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+/// Evaluates `base.pow(exp) % m` without overflowing
+/// 
+/// Uses exponentiation by squaring, with [`mulmod`] for every multiplication, so it takes
+/// `O(log(exp))` steps.
+/// 
+/// # Panics
+/// 
+/// Panics if `m` is zero.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use prime_data::powmod;
+/// 
+/// assert_eq!(powmod(2, 10, 1000), 24);
+/// assert_eq!(powmod(3, 0, 7), 1);
+/// assert_eq!(powmod(3, 0, 1), 0);
+/// // Fermat's little theorem: a^(p-1) = 1 (mod p)
+/// assert_eq!(powmod(2, 1_000_000_006, 1_000_000_007), 1);
+/// assert_eq!(powmod(u64::MAX, u64::MAX, 18_446_744_073_709_551_557), 4_959_809_447_704_153_900);
+/// ```
+pub fn powmod(base: u64, exp: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    let mut base = base % m;
+    let mut exp = exp;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mulmod(result, base, m);
+        }
+        base = mulmod(base, base, m);
+        exp >>= 1;
+    }
+
+    result
+}
+
+// Reverses the decimal digits of n, such that 1230 becomes 321, or None if the reversal doesn't fit in a u64
+pub fn reverse_decimal(n: u64) -> Option<u64> {
+    let mut n = n;
+    let mut reversed = 0u64;
+
+    while n > 0 {
+        reversed = reversed.checked_mul(10)?.checked_add(n % 10)?;
+        n /= 10;
+    }
+
+    Some(reversed)
+}
+
+// Whether the decimal digits of n read the same backwards, such as 7, 11, or 1221
+pub fn is_decimal_palindrome(n: u64) -> bool {
+    reverse_decimal(n) == Some(n)
+}
+
+// Greatest common divisor through the Euclidean algorithm, where gcd(0, 0) = 0
+#[cfg_attr(not(feature = "factors"), allow(dead_code))]
+pub fn gcd(a: u64, b: u64) -> u64 {
+    let (mut a, mut b) = (a, b);
+
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+// Deterministic Miller-Rabin primality test. Testing against the first 12 primes as bases is
+// enough to never be wrong for any u64, so this takes O(log(x)) steps instead of sieving up to √x.
+pub fn miller_rabin(x: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if x < 2 { return false }
+    for base in BASES {
+        if x == base { return true }
+        if x.divisible_by(base) { return false }
+    }
+
+    // x - 1 = d * 2^s, with d odd
+    let s = (x - 1).trailing_zeros();
+    let d = (x - 1) >> s;
+
+    'bases: for base in BASES {
+        let mut y = powmod(base, d, x);
+        if y == 1 || y == x - 1 { continue }
+
+        for _ in 1..s {
+            y = mulmod(y, y, x);
+            if y == x - 1 { continue 'bases }
+        }
+
+        return false
+    }
+
+    true
+}
+
+// Finds a nontrivial factor of some composite number through Brent's variant of Pollard's rho
+// algorithm. For a product of two primes, this takes around O(x^(1/4)) steps instead of O(√x).
+#[cfg_attr(not(feature = "factors"), allow(dead_code))]
+pub fn pollard_rho(x: u64) -> u64 {
+    // the sequence below only works for odd numbers
+    if x.divisible_by(2) { return 2 }
+
+    // how many differences are multiplied together before taking their gcd with x
+    const BATCH: u64 = 128;
+
+    #[cfg(feature = "tracing")]
+    let mut iterations = 0u64;
+
+    let mut c = 0;
+    let factor = loop {
+        c += 1;
+
+        // y² + c, where y < x, so the sum may only overflow by less than x
+        let next = |y: u64| {
+            let (sum, overflowed) = mulmod(y, y, x).overflowing_add(c);
+            if overflowed || sum >= x { sum.wrapping_sub(x) } else { sum }
+        };
+
+        let (mut y, mut saved_y, mut previous_y) = (2, 2, 2);
+        let (mut product, mut divisor, mut length) = (1, 1, 1);
+
+        while divisor == 1 {
+            previous_y = y;
+            for _ in 0..length { y = next(y) }
+
+            let mut steps = 0;
+            while steps < length && divisor == 1 {
+                saved_y = y;
+                for _ in 0..std::cmp::min(BATCH, length - steps) {
+                    y = next(y);
+                    product = mulmod(product, previous_y.abs_diff(y), x);
+                }
+
+                #[cfg(feature = "tracing")]
+                { iterations += std::cmp::min(BATCH, length - steps); }
+
+                divisor = gcd(product, x);
+                steps += BATCH;
+            }
+
+            length *= 2;
+        }
+
+        // the batch overshot, so we go back one step at a time
+        if divisor == x {
+            divisor = loop {
+                saved_y = next(saved_y);
+                let divisor = gcd(previous_y.abs_diff(saved_y), x);
+                if divisor > 1 { break divisor }
+            };
+        }
+
+        // otherwise, both sequences met before finding a factor, so we try again with another c
+        if divisor != x { break divisor }
+    };
+
+    #[cfg(feature = "tracing")]
+    tracing::trace!(iterations, "pollard's rho");
+
+    factor
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IntSqrt, Logarithm, Divisible, gcd, miller_rabin, pollard_rho, reverse_decimal, is_decimal_palindrome};
+    use std::cmp;
+
+    #[test]
+    fn logarithms_at_exact_powers() {
+        for exp in 0..=19u32 {
+            let power = 10u64.pow(exp);
+
+            assert_eq!(Logarithm::log10(power), exp as u64);
+            assert_eq!(Logarithm::log10(power - 1), (exp as u64).saturating_sub(1));
+            assert_eq!(Logarithm::log10(power + 1), exp as u64);
+        }
+
+        assert_eq!(Logarithm::log10(u64::MAX), 19);
+    }
+
+    #[test]
+    fn isqrt_around_large_squares() {
+        let roots = [
+            (1 << 26) - 1, 1 << 26, 94_906_265, 94_906_266, 999_999_999, 1_000_000_000,
+            3_037_000_499, 3_037_000_500, 4_294_967_294, 4_294_967_295,
+        ];
+
+        for root in roots {
+            let square: u64 = root * root;
+
+            assert_eq!(IntSqrt::isqrt(square), Ok(root));
+            assert_eq!(IntSqrt::isqrt(square - 1), Err(root - 1));
+            assert_eq!(IntSqrt::isqrt(square + 1), Err(root));
+            assert_eq!(IntSqrt::isqrt(square + 2 * root), Err(root));
+        }
+
+        // (2^32)^2 doesn't fit, so the largest square root is 2^32 - 1
+        assert_eq!(IntSqrt::isqrt(u64::MAX), Err(4_294_967_295));
+    }
+
+    #[test]
+    fn isqrt_small_numbers() {
+        for x in 0..100_000u64 {
+            let sqrt = x.sqrt_floor();
+            assert!(sqrt * sqrt <= x && x < (sqrt + 1) * (sqrt + 1));
+            assert_eq!(x.is_square(), sqrt * sqrt == x);
+        }
+    }
+
+    #[test]
+    fn miller_rabin_matches_trial_division() {
+        for x in 0..50_000u64 {
+            let is_prime = x > 1 && (2..=x.sqrt_floor()).all(|d| !x.divisible_by(d));
+            assert_eq!(miller_rabin(x), is_prime);
+        }
+
+        // strong pseudoprimes to several of the first prime bases
+        for composite in [2_047, 1_373_653, 25_326_001, 3_215_031_751, 3_825_123_056_546_413_051] {
+            assert!(!miller_rabin(composite));
+        }
+
+        for prime in [1_000_000_007, 4_294_967_291, 18_446_744_073_709_551_557] {
+            assert!(miller_rabin(prime));
+        }
+        assert!(!miller_rabin(4_294_967_297));
+        assert!(!miller_rabin(u64::MAX));
+    }
+
+    #[test]
+    fn gcd_matches_common_divisors() {
+        for a in 0..60u64 {
+            for b in 0..60u64 {
+                let naive = (1..=cmp::max(a, b)).filter(|&d| a.divisible_by(d) && b.divisible_by(d)).max().unwrap_or(0);
+                assert_eq!(gcd(a, b), naive);
+            }
+        }
+
+        assert_eq!(gcd(u64::MAX, u64::MAX - 1), 1);
+        assert_eq!(gcd(1 << 63, 1 << 40), 1 << 40);
+    }
+
+    #[test]
+    fn pollard_rho_splits_semiprimes() {
+        let semiprimes = [
+            (3, 5), (5, 7), (101, 103), (10_007, 65_537), (999_979, 999_983), (1_000_003, 4_294_967_291),
+            (4_294_967_279, 4_294_967_291), (2_147_483_647, 4_294_967_291),
+        ];
+
+        for (p, q) in semiprimes {
+            let factor = pollard_rho(p * q);
+            assert!(factor == p || factor == q, "{} is not a factor of {} * {}", factor, p, q);
+        }
+
+        // any nontrivial factor works for numbers with more than two
+        for x in [4, 6, 9, 27, 1155, 3 * 3 * 5 * 7 * 1_000_003, 1 << 63, u64::MAX] {
+            let factor = pollard_rho(x);
+            assert!(factor > 1 && factor < x && x.divisible_by(factor), "{} is not a factor of {}", factor, x);
+        }
+    }
+
+    #[test]
+    fn reverse_decimal_digits() {
+        assert_eq!(reverse_decimal(0), Some(0));
+        assert_eq!(reverse_decimal(7), Some(7));
+        assert_eq!(reverse_decimal(1230), Some(321));
+        assert_eq!(reverse_decimal(10_000_000_000_000_000_001), Some(10_000_000_000_000_000_001));
+        assert_eq!(reverse_decimal(9_999_999_999_999_999_991), Some(1_999_999_999_999_999_999));
+        assert_eq!(reverse_decimal(18_446_744_073_709_551_557), None);
+        assert_eq!(reverse_decimal(u64::MAX), None);
+    }
+
+    #[test]
+    fn decimal_palindromes() {
+        for n in [0, 7, 11, 121, 1221, 12321, 10_000_000_000_000_000_001] {
+            assert!(is_decimal_palindrome(n), "{}", n);
+        }
+        for n in [10, 12, 100, 1231, 18_446_744_073_709_551_557, u64::MAX] {
+            assert!(!is_decimal_palindrome(n), "{}", n);
+        }
+    }
+}
@@ -7,6 +7,9 @@ pub trait ContainsRange {
     type RangeDifference;
     fn contains_range(&self, range: &Self) -> Result<(), Self::RangeDifference>;
 
+    // the intersection of both ranges, or None if they're disjoint
+    fn overlap(&self, other: &Self) -> Option<RangeInclusive<u64>>;
+
     fn bounds(&self) -> (u64, u64);
 }
 
@@ -30,6 +33,16 @@ impl ContainsRange for RangeInclusive<u64> {
         Ok(())
     }
 
+    fn overlap(&self, other: &RangeInclusive<u64>) -> Option<RangeInclusive<u64>> {
+        let (self_start, self_end)   = self.bounds();
+        let (other_start, other_end) = other.bounds();
+
+        let start = std::cmp::max(self_start, other_start);
+        let end = std::cmp::min(self_end, other_end);
+
+        if start > end { None } else { Some(start..=end) }
+    }
+
     fn bounds(&self) -> (u64, u64) {
         (*(self.start()), *(self.end()))
     }
@@ -42,4 +55,28 @@ pub fn unwrap_any<T>(result: Result<T, T>) -> T {
         Ok(something) => something,
         Err(something) => something,
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ContainsRange;
+    use std::ops::RangeInclusive;
+
+    #[test]
+    fn overlap_cases() {
+        // fully contained
+        assert_eq!((0..=100).overlap(&(20..=30)), Some(20..=30));
+        assert_eq!((20..=30).overlap(&(0..=100)), Some(20..=30));
+        assert_eq!((5..=5).overlap(&(5..=5)), Some(5..=5));
+
+        // partial overlap
+        assert_eq!((0..=50).overlap(&(30..=100)), Some(30..=50));
+        assert_eq!((30..=100).overlap(&(0..=50)), Some(30..=50));
+        assert_eq!((0..=30).overlap(&(30..=60)), Some(30..=30));
+
+        // disjoint
+        assert_eq!((0..=29).overlap(&(30..=60)), None);
+        assert_eq!((61..=90).overlap(&(30..=60)), None);
+        assert_eq!((0..=100).overlap(&RangeInclusive::new(50, 40)), None);
+    }
+}
@@ -0,0 +1,182 @@
+//! Module dedicated to encoding data as the gaps between its primes
+use super::{PrimeData, PrimeByte, K_VALUES, error::*};
+
+impl PrimeData {
+    /// Encodes the gaps between consecutive primes in the data as [LEB128](https://en.wikipedia.org/wiki/LEB128)
+    /// varints
+    ///
+    /// Every gap below 128 takes a single byte, which covers nearly every gap you'll find below 10^18.
+    /// So, while the raw data takes one byte per 30 numbers, this takes around one byte per prime, which
+    /// makes it smaller for sparse data, starting somewhere around 10^13.
+    ///
+    /// The first prime is not encoded, so it has to be stored separately, and given back to
+    /// [`PrimeData::from_delta_varints`]. Neither is the range itself, so the decoded data ranges
+    /// from the first to the last prime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let data = PrimeData::generate(100..=130);
+    ///
+    /// // 101, 103, 107, 109, 113, 127
+    /// assert_eq!(data.to_delta_varints(), vec![2, 4, 2, 4, 14]);
+    /// ```
+    pub fn to_delta_varints(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut primes = self.iter_all();
+
+        let mut previous = match primes.next() {
+            Some(prime) => prime,
+            None => return bytes,
+        };
+
+        for prime in primes {
+            let mut gap = prime - previous;
+            while gap >= 0x80 {
+                bytes.push((gap as u8 & 0x7f) | 0x80);
+                gap >>= 7;
+            }
+            bytes.push(gap as u8);
+
+            previous = prime;
+        }
+
+        bytes
+    }
+
+    /// Decodes data from the gaps created by [`PrimeData::to_delta_varints`], given its first prime
+    ///
+    /// The decoded data ranges from `first_prime` to the last decoded prime. Every decoded number is
+    /// assumed to be prime, so make sure the bytes came from valid data.
+    ///
+    /// Returns an [OutOfBounds](crate::error::ErrorType::OutOfBounds) error with the position of the
+    /// first byte of an invalid varint, if one is cut short or doesn't fit in a [`u64`].
+    ///
+    /// Returns an [OutOfBounds](crate::error::ErrorType::OutOfBounds) error with the decoded number, if
+    /// it can't be prime, because it's divisible by 2, 3, or 5, or because it repeats the previous one.
+    ///
+    /// Returns a [TooLarge](crate::error::ErrorType::TooLarge) error if the data would take
+    /// more bytes than the [limit](crate::set_max_data_bytes).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let data = PrimeData::from_delta_varints(&[2, 4, 2, 4, 14], 101).unwrap();
+    ///
+    /// assert_eq!(data.range(), (101, 127));
+    /// assert_eq!(data.iter_all().collect::<Vec<u64>>(), vec![101, 103, 107, 109, 113, 127]);
+    ///
+    /// // 101 + 4 = 105, which is divisible by 5
+    /// assert!(PrimeData::from_delta_varints(&[4], 101).is_err());
+    /// // the continuation bit is set, but there are no more bytes
+    /// assert!(PrimeData::from_delta_varints(&[0x82], 101).is_err());
+    /// ```
+    pub fn from_delta_varints(bytes: &[u8], first_prime: u64) -> PrimeResult<Self> {
+        let error = |number| PrimeError {
+            context: ErrorContext { action: ErrorAction::Generating, source: ErrorSource::PrimeData, data_range: None, suggestion: None },
+            error: ErrorType::OutOfBounds(number)
+        };
+
+        let mut primes = vec![first_prime];
+        let mut position = 0;
+        while position < bytes.len() {
+            let varint_start = position;
+            let mut gap = 0u64;
+            let mut shift = 0;
+
+            loop {
+                let byte = *bytes.get(position).ok_or_else(|| error(varint_start as u64))?;
+                let bits = (byte & 0x7f) as u64;
+                if shift >= 64 || (bits << shift) >> shift != bits {
+                    return Err(error(varint_start as u64))
+                }
+
+                gap |= bits << shift;
+                shift += 7;
+                position += 1;
+
+                if byte & 0x80 == 0 { break }
+            }
+
+            let previous = primes[primes.len() - 1];
+            let prime = previous.checked_add(gap).ok_or_else(|| error(varint_start as u64))?;
+            primes.push(prime);
+        }
+
+        Self::from_increasing_primes(&primes)
+    }
+
+    /// Builds data ranging from the first to the last of the given primes, which must not be empty
+    ///
+    /// The numbers must be strictly increasing, but they're otherwise assumed to be prime. Returns an
+    /// [OutOfBounds](crate::error::ErrorType::OutOfBounds) error with the first number that breaks the
+    /// order, or that is divisible by 2, 3, or 5.
+    pub(crate) fn from_increasing_primes(primes: &[u64]) -> PrimeResult<Self> {
+        let error = |number| PrimeError {
+            context: ErrorContext { action: ErrorAction::Generating, source: ErrorSource::PrimeData, data_range: None, suggestion: None },
+            error: ErrorType::OutOfBounds(number)
+        };
+
+        if let Some(pair) = primes.windows(2).find(|pair| pair[0] >= pair[1]) {
+            return Err(error(pair[1]))
+        }
+
+        let range = primes[0]..=primes[primes.len() - 1];
+        Self::check_data_length(&range, ErrorAction::Generating)?;
+
+        // no prime is a multiple of 30, so the last one is always within the last byte
+        let offset = range.start() / 30;
+        let mut data = vec![0u8; (range.end() / 30 - offset + 1) as usize];
+        for &prime in primes {
+            if [2, 3, 5].contains(&prime) { continue }
+
+            // k-value 1 corresponds to the first bit, 7 to the second, and so on
+            let index = K_VALUES.binary_search(&((prime % 30) as u8)).map_err(|_| error(prime))?;
+            data[(prime / 30 - offset) as usize] |= 0x80 >> index;
+        }
+
+        let data = data.into_iter().map(PrimeByte::from).collect();
+
+        Ok(Self { data, range })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PrimeData;
+
+    #[test]
+    fn delta_varints_round_trip() {
+        for (start, end) in [(0, 1000), (2, 2), (5, 7), (31, 31), (1_000_000, 1_100_000), (1 << 40, (1 << 40) + 10_000)] {
+            let data = PrimeData::generate(start..=end);
+            let first_prime = data.iter_all().next().unwrap();
+            let decoded = PrimeData::from_delta_varints(&data.to_delta_varints(), first_prime).unwrap();
+
+            assert_eq!(decoded.iter_all().collect::<Vec<u64>>(), data.iter_all().collect::<Vec<u64>>(), "{}..={}", start, end);
+        }
+    }
+
+    #[test]
+    fn multi_byte_gaps() {
+        // 150 doesn't fit in 7 bits, so it takes two bytes
+        let bytes = [0x96, 0x01];
+        let data = PrimeData::from_delta_varints(&bytes, 7).unwrap();
+
+        assert_eq!(data.iter_all().collect::<Vec<u64>>(), vec![7, 157]);
+        assert_eq!(data.to_delta_varints(), bytes);
+
+        // overflows a u64
+        assert!(PrimeData::from_delta_varints(&[0xff; 10], 7).is_err());
+        assert!(PrimeData::from_delta_varints(&[0], 7).is_err());
+    }
+
+    #[test]
+    fn smaller_than_raw_bytes_when_sparse() {
+        let data = PrimeData::generate(1_000_000_000_000_000..=1_000_000_001_000_000);
+        let varints = data.to_delta_varints();
+
+        assert!(varints.len() < data.as_raw_bytes().len());
+    }
+}
@@ -0,0 +1,207 @@
+//! Module dedicated to streaming primes into writers
+use std::{io::{self, BufRead, Write}, ops::RangeInclusive};
+use super::{PrimeData, utils::IntSqrt, error::*};
+
+/// How many numbers are sieved at a time by [`write_primes_to`], which takes around 1MiB of data
+const SEGMENT_SIZE: u64 = 30 << 20;
+
+/// How each prime is encoded by [`write_primes_to`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PrimeFormat {
+    /// Every prime takes 8 bytes, as a little-endian [`u64`]
+    #[default]
+    LittleEndian,
+    /// Every prime is written in decimal, followed by a newline
+    Decimal,
+}
+
+/// Writes all prime numbers within the given range into some writer, returning how many were written
+/// 
+/// Unlike [generating](crate::PrimeData::generate) the whole range at once, this function sieves
+/// the range in segments of around 1MiB of data each, writing their primes before moving on to the next
+/// one. Therefore, it never holds more than a segment in memory (besides the primes up to the range's
+/// square root, which are needed for sieving), so it's suitable for writing, say, the first billion
+/// primes into a file.
+/// 
+/// This function writes a lot of small chunks, so you'll probably want to wrap your writer in a
+/// [`BufWriter`](std::io::BufWriter).
+/// 
+/// Returns any error the writer returns.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use prime_data::{write_primes_to, PrimeFormat};
+/// 
+/// let mut buffer = Vec::new();
+/// let written = write_primes_to(10..=30, &mut buffer, PrimeFormat::Decimal).unwrap();
+/// 
+/// assert_eq!(written, 6);
+/// assert_eq!(String::from_utf8(buffer).unwrap(), "11\n13\n17\n19\n23\n29\n");
+/// ```
+pub fn write_primes_to<W: Write>(range: RangeInclusive<u64>, writer: &mut W, format: PrimeFormat) -> io::Result<u64> {
+    let (start, end) = range.into_inner();
+    if start > end { return Ok(0) }
+
+    let base = PrimeData::generate(0..=end.sqrt_floor());
+
+    let mut written = 0;
+    let mut segment_start = start;
+    loop {
+        let segment_end = segment_start.saturating_add(SEGMENT_SIZE - 1).min(end);
+        let segment = base.expand(segment_start..=segment_end);
+
+        for prime in segment.iter_all() {
+            match format {
+                PrimeFormat::LittleEndian => writer.write_all(&prime.to_le_bytes())?,
+                PrimeFormat::Decimal => writeln!(writer, "{}", prime)?,
+            }
+            written += 1;
+        }
+
+        if segment_end == end { break }
+        segment_start = segment_end + 1;
+    }
+
+    Ok(written)
+}
+
+impl PrimeData {
+    /// Writes every prime in the data in decimal, one per line
+    ///
+    /// This is the same format as [`PrimeFormat::Decimal`], which is how most prime lists out there
+    /// are shared. It can be read back with [`PrimeData::read_text`].
+    ///
+    /// Returns any error the writer returns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prime_data::PrimeData;
+    /// let data = PrimeData::generate(10..=30);
+    ///
+    /// let mut buffer = Vec::new();
+    /// data.write_text(&mut buffer).unwrap();
+    ///
+    /// assert_eq!(String::from_utf8(buffer).unwrap(), "11\n13\n17\n19\n23\n29\n");
+    /// ```
+    pub fn write_text<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        for prime in self.iter_all() {
+            writeln!(writer, "{}", prime)?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads data from a list of primes in decimal, one per line
+    ///
+    /// The decoded data ranges from the first to the last prime. Blank lines and whitespace around
+    /// each number are ignored, but the numbers must be in increasing order. Every number is assumed
+    /// to be prime, so make sure the list is valid.
+    ///
+    /// Returns an [OutOfBounds](crate::error::ErrorType::OutOfBounds) error with the line number
+    /// (starting at 1), if that line can't be read or isn't a [`u64`], or with 0, if there are no primes.
+    ///
+    /// Returns an [OutOfBounds](crate::error::ErrorType::OutOfBounds) error with the number, if it
+    /// can't be prime, because it's divisible by 2, 3, or 5, or because it's not larger than the previous one.
+    ///
+    /// Returns a [TooLarge](crate::error::ErrorType::TooLarge) error if the data would take
+    /// more bytes than the [limit](crate::set_max_data_bytes).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use prime_data::PrimeData;
+    ///
+    /// let data = PrimeData::read_text("101\n103\n107\n109\n113\n127\n".as_bytes()).unwrap();
+    ///
+    /// assert_eq!(data.range(), (101, 127));
+    /// assert_eq!(data.count_primes(), 6);
+    ///
+    /// // 105 is divisible by 5
+    /// assert!(PrimeData::read_text("101\n105\n".as_bytes()).is_err());
+    /// // not a number
+    /// assert!(PrimeData::read_text("101\nprime\n".as_bytes()).is_err());
+    /// ```
+    pub fn read_text<R: BufRead>(reader: R) -> PrimeResult<Self> {
+        let error = |number| PrimeError {
+            context: ErrorContext { action: ErrorAction::Generating, source: ErrorSource::PrimeData, data_range: None, suggestion: None },
+            error: ErrorType::OutOfBounds(number)
+        };
+
+        let mut primes = Vec::new();
+        for (index, line) in reader.lines().enumerate() {
+            let line_number = index as u64 + 1;
+            let line = line.map_err(|_| error(line_number))?;
+            let line = line.trim();
+            if line.is_empty() { continue }
+
+            primes.push(line.parse().map_err(|_| error(line_number))?);
+        }
+
+        if primes.is_empty() { return Err(error(0)) }
+
+        Self::from_increasing_primes(&primes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn little_endian_round_trip() {
+        let mut buffer = Vec::new();
+        let written = write_primes_to(0..=100, &mut buffer, PrimeFormat::LittleEndian).unwrap();
+
+        let primes: Vec<u64> = buffer.chunks_exact(8)
+        .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+        .collect();
+
+        assert_eq!(written, 25);
+        assert_eq!(primes, PrimeData::generate(0..=100).iter_all().collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn segments_are_stitched() {
+        let range = (SEGMENT_SIZE - 1_000)..=(2 * SEGMENT_SIZE + 1_000);
+
+        let mut buffer = Vec::new();
+        let written = write_primes_to(range.clone(), &mut buffer, PrimeFormat::Decimal).unwrap();
+
+        let primes: Vec<u64> = String::from_utf8(buffer).unwrap()
+        .lines()
+        .map(|line| line.parse().unwrap())
+        .collect();
+
+        assert_eq!(written, primes.len() as u64);
+        assert_eq!(primes, PrimeData::generate(range).iter_all().collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn text_round_trip() {
+        use std::io::Cursor;
+
+        for (start, end) in [(0, 1000), (2, 2), (7, 7), (1_000_000, 1_100_000)] {
+            let data = PrimeData::generate(start..=end);
+
+            let mut cursor = Cursor::new(Vec::new());
+            data.write_text(&mut cursor).unwrap();
+            cursor.set_position(0);
+
+            let decoded = PrimeData::read_text(cursor).unwrap();
+            assert_eq!(decoded.iter_all().collect::<Vec<u64>>(), data.iter_all().collect::<Vec<u64>>(), "{}..={}", start, end);
+        }
+    }
+
+    #[test]
+    fn text_errors() {
+        let read = |text: &str| PrimeData::read_text(text.as_bytes()).map(|data| data.range());
+
+        assert_eq!(read(" 7\r\n\n11 \n").unwrap(), (7, 11));
+        assert!(matches!(read("").unwrap_err().error, ErrorType::OutOfBounds(0)));
+        assert!(matches!(read("7\n\n-11\n").unwrap_err().error, ErrorType::OutOfBounds(3)));
+        assert!(matches!(read("11\n7\n").unwrap_err().error, ErrorType::OutOfBounds(7)));
+        assert!(matches!(read("7\n7\n").unwrap_err().error, ErrorType::OutOfBounds(7)));
+    }
+}
@@ -411,13 +411,13 @@ pub mod iterators {
     /// option is that there are two situations in which we won't be able to retrieve that vector.
     /// 
     /// 1. If the range has no primes, the given vector will be empty. This means if we try to access
-    /// `self.primes[self.current.1]`, that will panic. Therefore, as a safety measure, we store it
-    /// as an option and set it to None when empty.
+    ///    `self.primes[self.current.1]`, that will panic. Therefore, as a safety measure, we store it
+    ///    as an option and set it to None when empty.
     /// 
     /// 2. If we're iterating through the data, we could encounter a prime byte that has no primes.
-    /// This means, when we iterate through the data, we need to keep incrememting `current.0` until
-    /// we hit a prime byte that is non empty. However, there's a chance we increase it to the point where
-    /// we hit the end of our slice. We return it as None and move on.
+    ///    This means, when we iterate through the data, we need to keep incrememting `current.0` until
+    ///    we hit a prime byte that is non empty. However, there's a chance we increase it to the point where
+    ///    we hit the end of our slice. We return it as None and move on.
     /// 
     /// In both situations, that's equivalent of "ending the iterator". From that, we will always
     /// yield None.
@@ -473,7 +473,9 @@ pub mod sieving {
         /// Then, all we have to do is calculate **pc** and the result of that multiplication is not a prime number!
         /// We just need to use some handy `data.set_nonprime(p*c)` function to flip the bit to zero. For a
         /// safer user experience, that function is private for PrimeData, but it [is](crate::PrimeByte::set_nonprime)
-        /// public for prime bytes, if you wish to create your own data struct using those.
+        /// public for prime bytes, if you wish to create your own data struct using those. If you really wish to
+        /// build your own sieve on top of PrimeData, there's also an
+        /// [unchecked](crate::PrimeData::set_nonprime_unchecked) version of it.
         ///
         /// That's it. That's all there is to it. Once we iterate over all **p** values, the expanded data is a valid
         /// data of prime numbers from 30 thousand to 50 thousand!
@@ -580,12 +582,6 @@ pub mod estimates {
 /// 
 /// # High Priority
 /// 
-/// ## Faster `is_prime(x)`
-/// 
-/// Currently, this function creates prime data up to the square root of `x`, then checking if none of the
-/// generated primes divide x. Unfortunately, this is way slower than it needs to be. Primality tests
-/// like Fermat or, the one I intend to implement, the Miller-Rabin test, are way faster.
-/// 
 /// ## More Rigorous Tests
 /// 
 /// Currently I'm running integration tests to make sure the data generation is working properly.
@@ -61,6 +61,12 @@ println!("2027 is {}", if prime_data::is_prime(2_027) { "prime!" } else { "not p
 
 // You can count the amount of primes from 1 to some bound
 println!("There are {} primes from 1 to 1024", prime_data::count_primes(1024));
+
+// Even for bounds way too big to sieve
+println!("There are {} primes from 1 to 10^10", prime_data::prime_pi(10_000_000_000));
+
+// Or retrieve the nth prime number
+println!("The 1000th prime number is {}", prime_data::nth_prime(1000));
 ```
 
 # Features
@@ -97,6 +103,39 @@ println!("The factors of 44 are {:?}", factorized_44.all_factors());
 // finally, if you only need to list a number's factors once:
 println!("The factors of 490 are {:?}", prime_data::all_factors_of(490));
 # }
+```
+
+## `"rayon"`
+
+The **rayon** feature makes big expansions run in parallel, using [rayon](https://docs.rs/rayon).
+Whenever the range being generated is wider than some threshold (10^8 by default), it's split into
+chunks that are sieved on all available cores. You can tune that threshold with
+`set_parallel_threshold`.
+
+```
+# #[cfg(feature = "rayon")] {
+// anything wider than 10 million numbers will now be generated in parallel
+prime_data::set_parallel_threshold(10_000_000);
+
+let data = prime_data::PrimeData::generate(0..=100_000_000);
+println!("There are {} primes below 100 million", data.count_primes());
+# }
+```
+
+## `"tracing"`
+
+The **tracing** feature instruments data generation with [tracing](https://docs.rs/tracing) spans and
+events, which is handy for diagnosing where the time goes when generating lots of data. Each call to
+[`generate`](crate::PrimeData::generate) and [`try_expand`](crate::PrimeData::try_expand) opens a span,
+and every prime used for sieving emits a `TRACE` event with how many multiples it crossed out.
+
+Without this feature, none of that code is compiled, so there's no overhead.
+
+## `"serde"`
+
+The **serde** feature implements [serde](https://docs.rs/serde)'s `Serialize` and `Deserialize` for
+[`Factorization`], when combined with the **factors** feature. It's serialized as its prime
+and exponent pairs, sorted by prime, which is handy for caching numbers that take a while to factorize.
 
 */
 
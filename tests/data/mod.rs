@@ -1,3 +1,5 @@
+#![allow(clippy::zero_prefixed_literal)]
+
 pub const SMALL_DATASET: [(u64, u64, u64); 100] = [
     (000, 000, 0), (001, 000, 0), (002, 001, 1), (018, 007, 0), (030, 010, 0), (049, 015, 0), (059, 017, 1), (069, 019, 0), (079, 022, 1), (082, 022, 0), 
     (090, 024, 0), (108, 028, 0), (109, 029, 1), (140, 034, 0), (142, 034, 0), (164, 038, 0), (183, 042, 0), (192, 043, 0), (193, 044, 1), (210, 046, 0), 
@@ -28,6 +28,8 @@ mod data;
 //             assert_eq![ data_count, count ]
 //         }
 //     },
+//     primepi = { for each n: assert_eq![ prime_pi(n), pi(n) ] },
+//     freeisp = { for each n: assert_eq![ is_prime(n), isprime(n) ] },
 // }
 
 #[test]
@@ -70,6 +72,58 @@ fn big_cmnrng() { functions::cmnrng(data::BIG_DATASET, 1_000_000) }
 #[ignore]
 fn big_genrng() { functions::genrng(data::BIG_DATASET) } // this test takes ~2 minutes 
 
+#[test]
+fn sml_primepi() { functions::primepi(data::SMALL_DATASET) }
+#[test]
+fn med_primepi() { functions::primepi(data::MEDIUM_DATASET) }
+#[test]
+fn big_primepi() { functions::primepi(data::BIG_DATASET) }
+
+#[test]
+fn sml_freeisp() { functions::freeisp(data::SMALL_DATASET) }
+#[test]
+fn med_freeisp() { functions::freeisp(data::MEDIUM_DATASET) }
+#[test]
+fn big_freeisp() { functions::freeisp(data::BIG_DATASET) }
+
+// these are way past the datasets, so they're compared against the known values of pi(10^n)
+#[test]
+fn powers_of_ten_primepi() {
+    let known = [
+        (10, 4), (100, 25), (1_000, 168), (10_000, 1_229), (100_000, 9_592), (1_000_000, 78_498),
+        (10_000_000, 664_579), (100_000_000, 5_761_455), (1_000_000_000, 50_847_534),
+        (10_000_000_000, 455_052_511), (100_000_000_000, 4_118_054_813),
+    ];
+
+    for (x, pi_x) in known {
+        assert_eq!(prime_data::prime_pi(x), pi_x);
+        assert_eq!(prime_data::estimate::prime_pi_exact(x), pi_x);
+    }
+}
+
+#[test]
+fn const_is_prime_matches_runtime() {
+    for n in (0..=100_000).chain(4_294_967_200..=4_294_967_400) {
+        assert_eq!(prime_data::is_prime_const(n), prime_data::is_prime(n), "{}", n);
+    }
+
+    // the largest prime below 2^40, along with its neighbours
+    for n in [1_099_511_627_689, 1_099_511_627_691, 1_099_511_627_693] {
+        assert_eq!(prime_data::is_prime_const(n), prime_data::is_prime(n), "{}", n);
+    }
+}
+
+// the biggest dataset that still generates in a few seconds, to make sure counting never goes astray
+#[test]
+fn largest_count_is_sane() {
+    let data = PrimeData::generate(0..=1_000_000_000);
+    let stats = data.stats();
+
+    assert_eq!(data.count_primes(), 50_847_534);
+    assert_eq!(data.count_primes(), data.count_primes_in_range(0..=1_000_000_000));
+    assert!(data.count_primes() <= 8 * stats.byte_count as u64 + 3);
+}
+
 mod functions {
 
     use super::PrimeData;
@@ -114,6 +168,17 @@ mod functions {
             }
         }
     }
+    pub fn primepi(dataset: [(u64, u64, u64); 100]) {
+        for &(n, pi_n, _) in dataset.iter() {
+            assert_eq!(prime_data::prime_pi(n), pi_n);
+            assert_eq!(prime_data::estimate::prime_pi_exact(n), pi_n);
+        }
+    }
+    pub fn freeisp(dataset: [(u64, u64, u64); 100]) {
+        for &(n, _, is_prime) in dataset.iter() {
+            assert_eq!(if prime_data::is_prime(n) { 1u64 } else { 0u64 }, is_prime);
+        }
+    }
     pub fn genrng(dataset: [(u64, u64, u64); 100]) {
         for &(n, pi_n, is_prime) in dataset.iter() {
             for &(m, pi_m, _) in dataset.iter() {
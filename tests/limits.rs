@@ -0,0 +1,33 @@
+// The data limit is global, so it's tested in its own binary, where raising it can't make some other
+// test allocate more than it expects
+use prime_data::{PrimeData, set_max_data_bytes, max_data_bytes, DEFAULT_MAX_DATA_BYTES, error::ErrorType};
+
+#[test]
+fn limit_can_be_overridden() {
+    assert_eq!(max_data_bytes(), DEFAULT_MAX_DATA_BYTES);
+
+    // around 10^12 numbers, which would take over 30GB
+    let wide_range = 0..=1_000_000_000_000;
+    assert!(PrimeData::try_generate(wide_range.clone()).is_err());
+    assert!(PrimeData::try_generate(0..=u64::MAX).is_err());
+
+    set_max_data_bytes(1_000);
+    let error = PrimeData::try_generate(0..=30_030).unwrap_err();
+    assert!(matches!(error.error, ErrorType::TooLarge { bytes: 1_001, limit: 1_000 }));
+
+    set_max_data_bytes(2_000);
+    assert_eq!(PrimeData::try_generate(0..=30_030).unwrap().count_primes(), 3_248);
+
+    // the message tells the limit the error was built with, not whatever it is now
+    assert!(error.to_string().contains("the limit is 1000"));
+
+    set_max_data_bytes(DEFAULT_MAX_DATA_BYTES);
+    assert!(PrimeData::try_generate(wide_range).is_err());
+}
+
+#[test]
+#[should_panic]
+fn prime_pi_exact_respects_limit() {
+    // √(2^64) counts of 16 bytes each take around 68GB, way more than any limit set here
+    prime_data::estimate::prime_pi_exact(u64::MAX);
+}
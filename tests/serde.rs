@@ -0,0 +1,37 @@
+#![cfg(all(feature = "factors", feature = "serde"))]
+
+use prime_data::Factorization;
+
+#[test]
+fn factorization_round_trip() {
+    // 43560 = 2^3 * 3^2 * 5 * 11^2
+    let factorization = Factorization::from(43560);
+
+    let json = serde_json::to_string(&factorization).unwrap();
+    assert_eq!(json, "[[2,3],[3,2],[5,1],[11,2]]");
+
+    let deserialized: Factorization = serde_json::from_str(&json).unwrap();
+    assert!(deserialized == factorization);
+    assert_eq!(deserialized.as_u64(), 43560);
+
+    let one: Factorization = serde_json::from_str("[]").unwrap();
+    assert_eq!(one.as_u64(), 1);
+}
+
+#[test]
+fn invalid_factorizations() {
+    for json in ["[[3,2],[2,3]]", "[[2,1],[2,1]]", "[[2,1],[9,1]]", "[[1,1]]", "[[2,0]]"] {
+        assert!(serde_json::from_str::<Factorization>(json).is_err(), "{}", json);
+    }
+
+    // the primes are fine on their own, but their product doesn't fit
+    for json in ["[[2,64]]", "[[3,41]]", "[[2,32],[4294967311,1]]"] {
+        match serde_json::from_str::<Factorization>(json) {
+            Err(error) => assert!(error.to_string().contains("factorization overflows u64"), "{}", json),
+            Ok(_) => panic!("{} should overflow", json),
+        }
+    }
+
+    let largest: Factorization = serde_json::from_str("[[2,63]]").unwrap();
+    assert_eq!(largest.as_u64(), 1 << 63);
+}
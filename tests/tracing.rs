@@ -0,0 +1,199 @@
+#![cfg(feature = "tracing")]
+
+use prime_data::{PrimeData, CoprimeIter};
+use std::{fmt, sync::{Arc, Mutex, atomic::{AtomicU64, Ordering}}};
+use tracing::{Event, Metadata, Subscriber, field::{Field, Visit}, span::{Attributes, Id, Record}, subscriber::Interest};
+
+// Minimal subscriber that records the names of every span, counts every event it receives, and
+// sums up the `multipliers`, `divisors`, `iterations`, and `steps` fields of those events
+#[derive(Default)]
+struct Records {
+    spans: Mutex<Vec<String>>,
+    events: AtomicU64,
+    multipliers: AtomicU64,
+    divisors: AtomicU64,
+    iterations: AtomicU64,
+    steps: AtomicU64,
+    next_id: AtomicU64,
+}
+
+#[derive(Clone, Default)]
+struct Recorder(Arc<Records>);
+
+impl Visit for &Records {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        match field.name() {
+            "multipliers" => { self.multipliers.fetch_add(value, Ordering::SeqCst); },
+            "divisors" => { self.divisors.fetch_add(value, Ordering::SeqCst); },
+            "iterations" => { self.iterations.fetch_add(value, Ordering::SeqCst); },
+            "steps" => { self.steps.fetch_add(value, Ordering::SeqCst); },
+            _ => {}
+        }
+    }
+
+    fn record_debug(&mut self, _: &Field, _: &dyn fmt::Debug) {}
+}
+
+impl Subscriber for Recorder {
+    fn register_callsite(&self, _: &'static Metadata<'static>) -> Interest { Interest::always() }
+    fn enabled(&self, _: &Metadata<'_>) -> bool { true }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        self.0.spans.lock().unwrap().push(span.metadata().name().to_string());
+        Id::from_u64(self.0.next_id.fetch_add(1, Ordering::SeqCst) + 1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+    fn event(&self, event: &Event<'_>) {
+        self.0.events.fetch_add(1, Ordering::SeqCst);
+        event.record(&mut &*self.0);
+    }
+    fn enter(&self, _: &Id) {}
+    fn exit(&self, _: &Id) {}
+}
+
+#[test]
+fn generation_emits_spans() {
+    let recorder = Recorder::default();
+
+    let data = tracing::subscriber::with_default(recorder.clone(), || PrimeData::generate(0..=10_000));
+    assert_eq!(data.count_primes(), 1229);
+
+    let spans = recorder.0.spans.lock().unwrap();
+    // 10_000 needs the primes up to 100, which are expanded straight from the starter data
+    assert_eq!(spans.iter().filter(|name| *name == "generate").count(), 1);
+    assert_eq!(spans.iter().filter(|name| *name == "try_expand").count(), 2);
+
+    // one event per prime in 7..=10 and 7..=100
+    assert_eq!(recorder.0.events.load(Ordering::SeqCst), 1 + 22);
+}
+
+#[test]
+fn sieving_skips_multipliers_below_prime() {
+    let recorder = Recorder::default();
+
+    let base = PrimeData::generate(0..=1_000);
+    let data = tracing::subscriber::with_default(recorder.clone(), || base.expand(0..=1_000_000));
+    assert_eq!(data.count_primes(), 78498);
+
+    // every multiplier from 7 onwards, which is what sieving used to cross out
+    let naive_multipliers: u64 = base.iter(7..=1_000)
+    .map(|prime| CoprimeIter::new(7..=(1_000_000 / prime)).count() as u64)
+    .sum();
+
+    let multipliers = recorder.0.multipliers.load(Ordering::SeqCst);
+    assert!(multipliers < naive_multipliers);
+}
+
+#[test]
+fn small_factors_skip_generation() {
+    let recorder = Recorder::default();
+
+    let results = tracing::subscriber::with_default(recorder.clone(), || {
+        [1u64 << 62, 3 * 1_000_000_007, 97 * 1_000_000_007, 10_007].map(prime_data::is_prime)
+    });
+    assert_eq!(results, [false, false, false, true]);
+    assert!(recorder.0.spans.lock().unwrap().is_empty());
+}
+
+#[test]
+fn big_numbers_skip_generation() {
+    let recorder = Recorder::default();
+
+    // the fifth Fermat number, 641 * 6_700_417, used to generate all primes up to 65_536
+    let results = tracing::subscriber::with_default(recorder.clone(), || {
+        [4_294_967_297, 101 * 1_000_000_007, 18_446_744_073_709_551_557, 4_294_967_291 * 4_294_967_279]
+        .map(prime_data::is_prime)
+    });
+    assert_eq!(results, [false, false, true, false]);
+    assert!(recorder.0.spans.lock().unwrap().is_empty());
+}
+
+#[cfg(feature = "factors")]
+#[test]
+fn factorizing_primes_skips_generation() {
+    use prime_data::Factorization;
+
+    let recorder = Recorder::default();
+
+    let prime = 18_446_744_073_709_551_557;
+    let factorization = tracing::subscriber::with_default(recorder.clone(), || Factorization::from(prime));
+    assert_eq!(factorization.as_tuples(), vec![(prime, 1)]);
+    assert!(recorder.0.spans.lock().unwrap().is_empty());
+
+    // composites still need the data
+    let factorization = tracing::subscriber::with_default(recorder.clone(), || Factorization::from(999_999_999_989 * 3));
+    assert_eq!(factorization.as_tuples(), vec![(3, 1), (999_999_999_989, 1)]);
+    assert!(recorder.0.spans.lock().unwrap().iter().any(|name| name == "generate"));
+}
+
+#[cfg(feature = "factors")]
+#[test]
+fn factorizing_stops_past_cube_root() {
+    let data = PrimeData::generate(0..=1_100_000);
+    // every trial division and every step of pollard's rho
+    let steps = |x: u64| {
+        let recorder = Recorder::default();
+        let factorization = tracing::subscriber::with_default(recorder.clone(), || data.factorize(x));
+
+        assert_eq!(factorization.as_u64(), x);
+        recorder.0.divisors.load(Ordering::SeqCst) + recorder.0.iterations.load(Ordering::SeqCst)
+    };
+
+    // dividing up to the square root would take all 78_498 primes below 10^6
+    let naive = data.count_primes_in_range(0..=1_000_000);
+
+    // 1009 is below the cube root, and the cofactor is prime
+    assert!(steps(1009 * 1_000_000_007) < 200);
+    // the square of a prime is found out right past the cube root of 10^12
+    assert!(steps(999_983 * 999_983) <= data.count_primes_in_range(0..=10_000));
+    // two primes of similar size are split by pollard's rho right past the cube root
+    for (p, q) in [(999_979, 999_983), (1_000_003, 1_000_033), (10_007, 99_999_989), (1_009, 999_999_937)] {
+        assert!(steps(p * q) < naive / 40, "too many steps for {} * {}", p, q);
+    }
+}
+
+#[test]
+fn expanding_within_data_skips_sieving() {
+    let recorder = Recorder::default();
+
+    let data = PrimeData::generate(0..=1000);
+    let expanded = tracing::subscriber::with_default(recorder.clone(), || [data.expand(0..=500), data.expand(0..=1000), data.expand(31..=59)]);
+
+    assert_eq!(expanded[0].iter_all().collect::<Vec<u64>>(), data.iter(0..=500).collect::<Vec<u64>>());
+    assert_eq!(expanded[0].range(), (0, 500));
+    assert_eq!(expanded[1].iter_all().collect::<Vec<u64>>(), data.iter_all().collect::<Vec<u64>>());
+    assert_eq!(expanded[2].iter_all().collect::<Vec<u64>>(), vec![31, 37, 41, 43, 47, 53, 59]);
+
+    // not a single prime was used for sieving
+    assert_eq!(recorder.0.events.load(Ordering::SeqCst), 0);
+    assert_eq!(recorder.0.multipliers.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn nth_prime_fast_takes_fewer_steps() {
+    let data = PrimeData::generate(0..=30_000_000);
+
+    let steps = |nth_prime: &dyn Fn() -> u64| {
+        let recorder = Recorder::default();
+        let prime = tracing::subscriber::with_default(recorder.clone(), nth_prime);
+        (prime, recorder.0.steps.load(Ordering::SeqCst))
+    };
+
+    let (mut iterated, mut searched) = (0, 0);
+    for nth in (10_000..=1_857_859).step_by(18_475) {
+        let (prime, nth_steps) = steps(&|| data.nth_prime(nth));
+        let (fast_prime, fast_steps) = steps(&|| data.nth_prime_fast(nth).unwrap());
+        assert_eq!(fast_prime, prime);
+
+        // binary searching the counts of 1_000_000 bytes, which takes around log2(1_000_001) probes
+        assert!(fast_steps <= 21);
+        assert!(fast_steps < nth_steps);
+
+        iterated += nth_steps;
+        searched += fast_steps;
+    }
+
+    assert!(10 * searched < iterated);
+}